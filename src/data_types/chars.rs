@@ -6,11 +6,23 @@
 use core::fmt;
 
 /// Character conversion error
-#[derive(Clone, Copy, Debug)]
+///
+/// Returned when a character cannot be represented in the target encoding,
+/// such as a `char` outside the Basic Multilingual Plane for UCS-2, or a
+/// `char` above U+00FF for Latin-1.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub struct CharConversionError;
 
+impl fmt::Display for CharConversionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("character is not representable in the target encoding")
+    }
+}
+
+impl core::error::Error for CharConversionError {}
+
 /// A Latin-1 character
-#[derive(Clone, Copy, Default, Eq, PartialEq, PartialOrd, Ord)]
+#[derive(Clone, Copy, Default, Eq, PartialEq, PartialOrd, Ord, Hash)]
 #[repr(transparent)]
 pub struct Char8(u8);
 
@@ -61,10 +73,31 @@ impl fmt::Display for Char8 {
 pub const NUL_8: Char8 = Char8(0);
 
 /// An UCS-2 code point
-#[derive(Clone, Copy, Default, Eq, PartialEq, PartialOrd, Ord)]
+#[derive(Clone, Copy, Default, Eq, PartialEq, PartialOrd, Ord, Hash)]
 #[repr(transparent)]
 pub struct Char16(u16);
 
+impl Char16 {
+    /// Create a `Char16` from a UCS-2 code point, or return `None` if it is
+    /// a UTF-16 surrogate (0xD800 to 0xDFFF), which is not a valid character.
+    pub const fn from_u16(value: u16) -> Option<Self> {
+        if value >= 0xd800 && value <= 0xdfff {
+            None
+        } else {
+            Some(Char16(value))
+        }
+    }
+
+    /// Create a `Char16` from a UCS-2 code point without checking it.
+    ///
+    /// # Safety
+    ///
+    /// `value` must not be a UTF-16 surrogate (0xD800 to 0xDFFF).
+    pub const unsafe fn new_unchecked(value: u16) -> Self {
+        Char16(value)
+    }
+}
+
 impl TryFrom<char> for Char16 {
     type Error = CharConversionError;
 
@@ -88,13 +121,7 @@ impl TryFrom<u16> for Char16 {
     type Error = CharConversionError;
 
     fn try_from(value: u16) -> Result<Self, Self::Error> {
-        // We leverage char's TryFrom<u32> impl for Unicode validity checking
-        let res: Result<char, _> = u32::from(value).try_into();
-        if let Ok(ch) = res {
-            ch.try_into()
-        } else {
-            Err(CharConversionError)
-        }
+        Self::from_u16(value).ok_or(CharConversionError)
     }
 }
 
@@ -126,3 +153,38 @@ impl fmt::Display for Char16 {
 
 /// UCS-2 version of the NUL character
 pub const NUL_16: Char16 = Char16(0);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_char16_from_char() {
+        assert_eq!(Char16::try_from('A'), Ok(Char16(0x41)));
+        assert_eq!(Char16::try_from('€'), Ok(Char16(0x20ac)));
+        assert_eq!(Char16::try_from('\0'), Ok(NUL_16));
+        assert_eq!(Char16::try_from('😀'), Err(CharConversionError));
+
+        assert_eq!(char::from(Char16::try_from('€').unwrap()), '€');
+    }
+
+    #[test]
+    fn test_char16_from_u16() {
+        assert_eq!(Char16::from_u16(0x41), Some(Char16(0x41)));
+        assert_eq!(Char16::from_u16(0xd7ff), Some(Char16(0xd7ff)));
+        assert_eq!(Char16::from_u16(0xd800), None);
+        assert_eq!(Char16::from_u16(0xdfff), None);
+        assert_eq!(Char16::from_u16(0xe000), Some(Char16(0xe000)));
+        assert_eq!(Char16::from_u16(0xffff), Some(Char16(0xffff)));
+
+        assert_eq!(Char16::try_from(0xd83d_u16), Err(CharConversionError));
+        assert_eq!(u16::from(unsafe { Char16::new_unchecked(0x20ac) }), 0x20ac);
+    }
+
+    #[test]
+    fn test_char8_from_char() {
+        assert_eq!(Char8::try_from('A'), Ok(Char8(0x41)));
+        assert_eq!(Char8::try_from('ÿ'), Ok(Char8(0xff)));
+        assert_eq!(Char8::try_from('€'), Err(CharConversionError));
+    }
+}
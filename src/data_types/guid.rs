@@ -1,4 +1,5 @@
 use core::fmt;
+use core::str::FromStr;
 
 /// A globally unique identifier
 ///
@@ -6,9 +7,14 @@ use core::fmt;
 /// mostly like variant 2 UUIDs as specified by RFC 4122, but differ from them
 /// in that the first 3 fields are little endian instead of big endian.
 ///
-/// The `Display` formatter prints GUIDs in the canonical format defined by
-/// RFC 4122, which is also used by UEFI.
-#[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
+/// The `Display` and `LowerHex` formatters print GUIDs in the canonical format
+/// defined by RFC 4122, which is also used by UEFI. The same format can be
+/// parsed back using `FromStr`.
+///
+/// GUIDs are ordered like their canonical textual representation, which is
+/// the same as comparing the fields in big endian order. This ordering is not
+/// the same as comparing the in-memory representation.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
 #[repr(C)]
 pub struct Guid {
     /// The low field of the timestamp.
@@ -54,6 +60,40 @@ impl Guid {
             ],
         }
     }
+
+    /// Create a GUID from its 16-byte in-memory representation
+    ///
+    /// This is the mixed-endian layout of `EFI_GUID`: the first three fields
+    /// (`time_low`, `time_mid` and `time_high_and_version`) are stored little
+    /// endian, so their bytes are swapped compared to the canonical textual
+    /// form. The last eight bytes (`clock_seq_and_variant` and `node`) are
+    /// stored in the same order as in the textual form.
+    pub const fn from_bytes(bytes: [u8; 16]) -> Self {
+        Guid {
+            a: u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]),
+            b: u16::from_le_bytes([bytes[4], bytes[5]]),
+            c: u16::from_le_bytes([bytes[6], bytes[7]]),
+            d: [
+                bytes[8], bytes[9], bytes[10], bytes[11], bytes[12], bytes[13], bytes[14],
+                bytes[15],
+            ],
+        }
+    }
+
+    /// Get the 16-byte in-memory representation of this GUID
+    ///
+    /// See `from_bytes` for a description of the layout. The result matches
+    /// a dump of the equivalent `EFI_GUID` in EDK2.
+    pub const fn to_bytes(&self) -> [u8; 16] {
+        let a = self.a.to_le_bytes();
+        let b = self.b.to_le_bytes();
+        let c = self.c.to_le_bytes();
+        let d = self.d;
+        [
+            a[0], a[1], a[2], a[3], b[0], b[1], c[0], c[1], d[0], d[1], d[2], d[3], d[4], d[5],
+            d[6], d[7],
+        ]
+    }
 }
 
 impl fmt::Display for Guid {
@@ -79,6 +119,65 @@ impl fmt::Display for Guid {
     }
 }
 
+impl fmt::LowerHex for Guid {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(self, fmt)
+    }
+}
+
+/// Byte offsets of the hyphens in the canonical textual form of a GUID
+const GUID_HYPHEN_POSITIONS: [usize; 4] = [8, 13, 18, 23];
+
+/// Length of the canonical textual form of a GUID
+const GUID_STR_LEN: usize = 36;
+
+/// Errors which can occur when parsing a `Guid` from a string
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum GuidFromStrError {
+    /// The string was not exactly 36 bytes long
+    InvalidLength,
+
+    /// A hyphen was missing at the given byte offset
+    InvalidHyphen(usize),
+
+    /// A non-hexadecimal character was encountered at the given byte offset
+    InvalidHexDigit(usize),
+}
+
+impl FromStr for Guid {
+    type Err = GuidFromStrError;
+
+    /// Parse a GUID in the canonical `xxxxxxxx-xxxx-xxxx-xxxx-xxxxxxxxxxxx`
+    /// form. Both lowercase and uppercase hexadecimal digits are accepted.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let bytes = s.as_bytes();
+        if bytes.len() != GUID_STR_LEN {
+            return Err(GuidFromStrError::InvalidLength);
+        }
+
+        for (i, byte) in bytes.iter().enumerate() {
+            if GUID_HYPHEN_POSITIONS.contains(&i) {
+                if *byte != b'-' {
+                    return Err(GuidFromStrError::InvalidHyphen(i));
+                }
+            } else if !byte.is_ascii_hexdigit() {
+                return Err(GuidFromStrError::InvalidHexDigit(i));
+            }
+        }
+
+        // All fields are now known to be valid hexadecimal numbers
+        let field = |range: core::ops::Range<usize>| u64::from_str_radix(&s[range], 16).unwrap();
+
+        Ok(Guid::from_values(
+            field(0..8) as u32,
+            field(9..13) as u16,
+            field(14..18) as u16,
+            field(19..23) as u16,
+            field(24..36),
+        ))
+    }
+}
+
 /// Several entities in the UEFI specification can be referred to by their GUID,
 /// this trait is a building block to interface them in uefi-rs.
 ///
@@ -101,16 +200,28 @@ impl fmt::Display for Guid {
 /// #[unsafe_guid("12345678-9abc-def0-1234-56789abcdef0")]
 /// struct Emptiness;
 /// ```
+///
+/// The GUID can also be given as an array of 16 bytes in UEFI's mixed-endian
+/// layout, where the first three fields are little endian:
+///
+/// ```
+/// use uefi::unsafe_guid;
+/// #[unsafe_guid([
+///     0x78, 0x56, 0x34, 0x12, 0xbc, 0x9a, 0xf0, 0xde,
+///     0x12, 0x34, 0x56, 0x78, 0x9a, 0xbc, 0xde, 0xf0,
+/// ])]
+/// struct Emptiness;
+/// ```
 pub unsafe trait Identify {
     /// Unique protocol identifier.
     const GUID: Guid;
 }
 
-pub use uefi_macros::unsafe_guid;
+pub use uefi_macros::{guid, unsafe_guid};
 
 #[cfg(test)]
 mod tests {
-    use uefi::unsafe_guid;
+    use uefi::{guid, unsafe_guid};
     extern crate alloc;
     use super::*;
 
@@ -125,6 +236,144 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_guid_lower_hex() {
+        assert_eq!(
+            alloc::format!("{:x}", crate::table::cfg::ACPI2_GUID),
+            "8868e871-e4f1-11d3-bc22-0080c73c8881"
+        );
+    }
+
+    #[test]
+    fn test_guid_from_str() {
+        let guid = Guid::from_values(0x12345678, 0x9abc, 0xdef0, 0x1234, 0x56789abcdef0);
+        assert_eq!("12345678-9abc-def0-1234-56789abcdef0".parse(), Ok(guid));
+        assert_eq!("12345678-9ABC-DEF0-1234-56789ABCDEF0".parse(), Ok(guid));
+
+        // Round trip through the canonical textual form
+        for guid in [
+            crate::table::cfg::ACPI_GUID,
+            crate::table::cfg::ACPI2_GUID,
+            crate::table::cfg::SMBIOS3_GUID,
+            Guid::default(),
+        ] {
+            assert_eq!(alloc::format!("{}", guid).parse(), Ok(guid));
+        }
+    }
+
+    #[test]
+    fn test_guid_from_str_errors() {
+        assert_eq!("".parse::<Guid>(), Err(GuidFromStrError::InvalidLength));
+        assert_eq!(
+            "12345678-9abc-def0-1234-56789abcdef".parse::<Guid>(),
+            Err(GuidFromStrError::InvalidLength)
+        );
+        assert_eq!(
+            "12345678-9abc-def0-1234-56789abcdef00".parse::<Guid>(),
+            Err(GuidFromStrError::InvalidLength)
+        );
+        assert_eq!(
+            "123456789-abc-def0-1234-56789abcdef0".parse::<Guid>(),
+            Err(GuidFromStrError::InvalidHyphen(8))
+        );
+        assert_eq!(
+            "12345678-9abc-def0-1234+56789abcdef0".parse::<Guid>(),
+            Err(GuidFromStrError::InvalidHyphen(23))
+        );
+        assert_eq!(
+            "12345678-9abc-defg-1234-56789abcdef0".parse::<Guid>(),
+            Err(GuidFromStrError::InvalidHexDigit(17))
+        );
+        assert_eq!(
+            "+2345678-9abc-def0-1234-56789abcdef0".parse::<Guid>(),
+            Err(GuidFromStrError::InvalidHexDigit(0))
+        );
+    }
+
+    #[test]
+    fn test_guid_ordering() {
+        use crate::table::cfg::*;
+
+        let mut guids = alloc::vec![
+            ACPI2_GUID,
+            SMBIOS_GUID,
+            ACPI_GUID,
+            SMBIOS3_GUID,
+            DXE_SERVICES_GUID,
+            Guid::default(),
+        ];
+        guids.sort();
+        assert_eq!(
+            guids,
+            [
+                Guid::default(),
+                DXE_SERVICES_GUID,
+                ACPI2_GUID,
+                ACPI_GUID,
+                SMBIOS_GUID,
+                SMBIOS3_GUID,
+            ]
+        );
+
+        // The order matches the order of the canonical textual form.
+        let strings: alloc::vec::Vec<_> = guids.iter().map(|g| alloc::format!("{}", g)).collect();
+        let mut sorted_strings = strings.clone();
+        sorted_strings.sort();
+        assert_eq!(strings, sorted_strings);
+
+        // The last byte is the least significant one.
+        assert!(
+            guid!("00000000-0000-0000-0000-000000000001")
+                < guid!("10000000-0000-0000-0000-000000000000")
+        );
+        assert!(
+            guid!("00000001-0000-0000-0000-000000000000")
+                < guid!("00000100-0000-0000-0000-000000000000")
+        );
+    }
+
+    #[test]
+    fn test_guid_hash() {
+        extern crate std;
+        use std::collections::HashSet;
+
+        let set: HashSet<Guid> = [
+            crate::table::cfg::ACPI_GUID,
+            crate::table::cfg::ACPI2_GUID,
+            crate::table::cfg::ACPI_GUID,
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(set.len(), 2);
+        assert!(set.contains(&crate::table::cfg::ACPI2_GUID));
+    }
+
+    #[test]
+    fn test_guid_bytes() {
+        let acpi2_bytes = [
+            0x71, 0xe8, 0x68, 0x88, 0xf1, 0xe4, 0xd3, 0x11, 0xbc, 0x22, 0x00, 0x80, 0xc7, 0x3c,
+            0x88, 0x81,
+        ];
+        assert_eq!(crate::table::cfg::ACPI2_GUID.to_bytes(), acpi2_bytes);
+        assert_eq!(Guid::from_bytes(acpi2_bytes), crate::table::cfg::ACPI2_GUID);
+
+        // The byte representation matches the in-memory layout.
+        let guid = crate::table::cfg::SMBIOS3_GUID;
+        let raw: [u8; 16] = unsafe { core::mem::transmute(guid) };
+        assert_eq!(guid.to_bytes(), raw);
+        assert_eq!(Guid::from_bytes(guid.to_bytes()), guid);
+    }
+
+    #[test]
+    fn test_guid_macro() {
+        const X: Guid = guid!("12345678-9abc-def0-1234-56789abcdef0");
+
+        assert_eq!(
+            X,
+            Guid::from_values(0x12345678, 0x9abc, 0xdef0, 0x1234, 0x56789abcdef0)
+        );
+    }
+
     #[test]
     fn test_unsafe_guid() {
         #[unsafe_guid("12345678-9abc-def0-1234-56789abcdef0")]
@@ -135,4 +384,15 @@ mod tests {
             Guid::from_values(0x12345678, 0x9abc, 0xdef0, 0x1234, 0x56789abcdef0)
         );
     }
+
+    #[test]
+    fn test_unsafe_guid_bytes() {
+        #[unsafe_guid([
+            0x30, 0x2d, 0x9d, 0xeb, 0x88, 0x2d, 0xd3, 0x11, 0x9a, 0x16, 0x00, 0x90, 0x27, 0x3f,
+            0xc1, 0x4d,
+        ])]
+        struct X;
+
+        assert_eq!(X::GUID, crate::table::cfg::ACPI_GUID);
+    }
 }
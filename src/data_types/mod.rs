@@ -7,7 +7,7 @@ use core::{ffi::c_void, ptr::NonNull};
 /// Opaque handle to an UEFI entity (protocol, image...), guaranteed to be non-null.
 ///
 /// If you need to have a nullable handle (for a custom UEFI FFI for example) use `Option<Handle>`.
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
 #[repr(transparent)]
 pub struct Handle(NonNull<c_void>);
 
@@ -54,6 +54,10 @@ impl Event {
     }
 }
 
+/// Physical memory address. This is always a 64-bit value, regardless
+/// of target platform.
+pub type PhysicalAddress = u64;
+
 /// Trait for querying the alignment of a struct.
 ///
 /// For a statically-sized type the alignment can be retrieved with
@@ -68,6 +72,37 @@ pub trait Align {
     /// Required memory alignment for this type
     fn alignment() -> usize;
 
+    /// Calculate the offset from `val` necessary to make it aligned,
+    /// rounding up. For example, if `val` is 1 and the alignment is 8,
+    /// this will return 7. Returns 0 if `val` is already aligned.
+    fn offset_up_to_alignment(val: usize) -> usize {
+        let alignment = Self::alignment();
+        assert_ne!(alignment, 0);
+        match val % alignment {
+            0 => 0,
+            r => alignment - r,
+        }
+    }
+
+    /// Round `val` up so that it is aligned.
+    ///
+    /// This can be used to compute how much storage to allocate for a type
+    /// whose size is known, such as the size reported by
+    /// `FileInfoCreationError::InsufficientStorage`.
+    fn round_up_to_alignment(val: usize) -> usize {
+        val + Self::offset_up_to_alignment(val)
+    }
+
+    /// Get a subslice of `buf` where the address of the first element is
+    /// aligned. Returns `None` if no element of the buffer is aligned.
+    ///
+    /// A buffer of `size + Self::alignment() - 1` bytes always contains an
+    /// aligned subslice of at least `size` bytes.
+    fn align_buf(buf: &mut [u8]) -> Option<&mut [u8]> {
+        let offset = Self::offset_up_to_alignment(buf.as_ptr() as usize);
+        buf.get_mut(offset..)
+    }
+
     /// Assert that some storage is correctly aligned for this type
     fn assert_aligned(storage: &mut [u8]) {
         if !storage.is_empty() {
@@ -81,8 +116,8 @@ pub trait Align {
 }
 
 mod guid;
-pub use self::guid::Guid;
-pub use self::guid::{unsafe_guid, Identify};
+pub use self::guid::{guid, unsafe_guid, Identify};
+pub use self::guid::{Guid, GuidFromStrError};
 
 pub mod chars;
 pub use self::chars::{Char16, Char8};
@@ -91,9 +126,77 @@ pub use self::chars::{Char16, Char8};
 mod enums;
 
 mod strs;
-pub use self::strs::{CStr16, CStr8, FromSliceWithNulError, FromStrWithBufError};
+pub use self::strs::{
+    CStr16, CStr16Split, CStr8, CStr8Iter, FromSliceWithNulError, FromStrWithBufError,
+};
+pub use uefi_macros::cstr16;
 
 #[cfg(feature = "exts")]
 mod owned_strs;
 #[cfg(feature = "exts")]
 pub use self::owned_strs::{CString16, FromStrError};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Align1;
+    struct Align4;
+    struct Align8;
+
+    impl Align for Align1 {
+        fn alignment() -> usize {
+            1
+        }
+    }
+
+    impl Align for Align4 {
+        fn alignment() -> usize {
+            4
+        }
+    }
+
+    impl Align for Align8 {
+        fn alignment() -> usize {
+            8
+        }
+    }
+
+    #[test]
+    fn test_alignment() {
+        // 1-byte alignment never changes anything.
+        for val in [0, 1, 7, 8, 9] {
+            assert_eq!(Align1::offset_up_to_alignment(val), 0);
+            assert_eq!(Align1::round_up_to_alignment(val), val);
+        }
+
+        assert_eq!(Align4::offset_up_to_alignment(0), 0);
+        assert_eq!(Align4::offset_up_to_alignment(1), 3);
+        assert_eq!(Align4::offset_up_to_alignment(4), 0);
+        assert_eq!(Align4::offset_up_to_alignment(6), 2);
+        assert_eq!(Align4::round_up_to_alignment(0), 0);
+        assert_eq!(Align4::round_up_to_alignment(1), 4);
+        assert_eq!(Align4::round_up_to_alignment(4), 4);
+        assert_eq!(Align4::round_up_to_alignment(6), 8);
+
+        assert_eq!(Align8::offset_up_to_alignment(1), 7);
+        assert_eq!(Align8::offset_up_to_alignment(8), 0);
+        assert_eq!(Align8::offset_up_to_alignment(100), 4);
+        assert_eq!(Align8::round_up_to_alignment(1), 8);
+        assert_eq!(Align8::round_up_to_alignment(100), 104);
+    }
+
+    #[test]
+    fn test_align_buf() {
+        let mut storage = [0u64; 4];
+        let buf = unsafe { core::slice::from_raw_parts_mut(storage.as_mut_ptr() as *mut u8, 32) };
+
+        // Skip the first byte so that the buffer starts misaligned.
+        let aligned = Align8::align_buf(&mut buf[1..]).unwrap();
+        assert_eq!(aligned.as_ptr() as usize % 8, 0);
+        assert_eq!(aligned.len(), 24);
+
+        // There is no aligned element in a short, misaligned buffer.
+        assert!(Align8::align_buf(&mut buf[1..4]).is_none());
+    }
+}
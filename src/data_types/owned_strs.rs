@@ -1,10 +1,15 @@
 use super::chars::{Char16, NUL_16};
 use super::strs::CStr16;
-use crate::alloc_api::vec::Vec;
+use crate::alloc_api::{vec, vec::Vec};
+use core::borrow::Borrow;
+use core::cmp::Ordering;
 use core::fmt;
+use core::hash::{Hash, Hasher};
 use core::ops;
+use core::str::FromStr;
 
-/// Error returned by [`CString16::try_from::<&str>`].
+/// Error returned by [`CString16::try_from::<&str>`] and the methods which
+/// append to a `CString16`.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum FromStrError {
     /// Character conversion error.
@@ -25,9 +30,152 @@ pub enum FromStrError {
 /// let s = CString16::try_from("abc").unwrap();
 /// assert_eq!(s.to_string(), "abc");
 /// ```
-#[derive(Clone, Debug, Default, Eq, PartialEq, Ord, PartialOrd)]
+///
+/// Building a string piece by piece:
+///
+/// ```
+/// use uefi::CString16;
+///
+/// let mut path = CString16::try_from("EFI").unwrap();
+/// path.push('\\').unwrap();
+/// path.push_str("BOOT").unwrap();
+/// assert_eq!(path.to_string(), "EFI\\BOOT");
+/// ```
+///
+/// Collecting an iterator of characters:
+///
+/// ```
+/// use uefi::CString16;
+///
+/// let s: CString16 = "abc".chars().rev().collect();
+/// assert_eq!(s.to_string(), "cba");
+///
+/// // Use `try_from_iter` for characters which may not be valid UCS-2.
+/// assert!(CString16::try_from_iter("😀".chars()).is_err());
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub struct CString16(Vec<Char16>);
 
+impl CString16 {
+    /// Create a string from an iterator of characters.
+    ///
+    /// Unlike the `FromIterator` implementation, this returns an error if
+    /// any character can't be represented in UCS-2 or is null.
+    pub fn try_from_iter<I: IntoIterator<Item = char>>(iter: I) -> Result<Self, FromStrError> {
+        let mut s = Self::default();
+        for ch in iter {
+            s.push(ch)?;
+        }
+        Ok(s)
+    }
+
+    /// Append a character to the end of the string.
+    ///
+    /// Characters outside of the Basic Multilingual Plane can't be
+    /// represented in UCS-2, and strings can't contain null characters. In
+    /// both cases, an error is returned and the string is left unchanged.
+    pub fn push(&mut self, ch: char) -> Result<(), FromStrError> {
+        let ch = Char16::try_from(ch).map_err(|_| FromStrError::InvalidChar)?;
+        if ch == NUL_16 {
+            return Err(FromStrError::InteriorNul);
+        }
+        self.push_char16(ch);
+        Ok(())
+    }
+
+    /// Append a string slice to the end of the string.
+    ///
+    /// If any character of `s` can't be appended (see `push`), an error is
+    /// returned and the string is left unchanged.
+    pub fn push_str(&mut self, s: &str) -> Result<(), FromStrError> {
+        let len = self.0.len();
+        for ch in s.chars() {
+            if let Err(err) = self.push(ch) {
+                self.0.truncate(len - 1);
+                self.0.push(NUL_16);
+                return Err(err);
+            }
+        }
+        Ok(())
+    }
+
+    /// Append another UCS-2 string to the end of the string.
+    pub fn push_cstr16(&mut self, other: &CStr16) {
+        for &ch in other.as_slice() {
+            self.push_char16(ch);
+        }
+    }
+
+    /// Append a character, which must not be null, moving the null
+    /// terminator after it.
+    fn push_char16(&mut self, ch: Char16) {
+        self.0.pop();
+        self.0.push(ch);
+        self.0.push(NUL_16);
+    }
+}
+
+impl Default for CString16 {
+    fn default() -> Self {
+        CString16(vec![NUL_16])
+    }
+}
+
+/// Collect characters into a `CString16`.
+///
+/// # Panics
+///
+/// Panics if a character can't be represented in UCS-2 or is null. Use
+/// [`CString16::try_from_iter`] to handle these cases without panicking.
+impl FromIterator<char> for CString16 {
+    fn from_iter<I: IntoIterator<Item = char>>(iter: I) -> Self {
+        let mut s = Self::default();
+        s.extend(iter);
+        s
+    }
+}
+
+/// Collect UCS-2 characters into a `CString16`.
+///
+/// # Panics
+///
+/// Panics if a character is null.
+impl FromIterator<Char16> for CString16 {
+    fn from_iter<I: IntoIterator<Item = Char16>>(iter: I) -> Self {
+        let mut s = Self::default();
+        s.extend(iter);
+        s
+    }
+}
+
+/// Append characters to a `CString16`.
+///
+/// # Panics
+///
+/// Panics if a character can't be represented in UCS-2 or is null.
+impl Extend<char> for CString16 {
+    fn extend<I: IntoIterator<Item = char>>(&mut self, iter: I) {
+        for ch in iter {
+            self.push(ch)
+                .expect("character cannot be added to a CString16");
+        }
+    }
+}
+
+/// Append UCS-2 characters to a `CString16`.
+///
+/// # Panics
+///
+/// Panics if a character is null.
+impl Extend<Char16> for CString16 {
+    fn extend<I: IntoIterator<Item = Char16>>(&mut self, iter: I) {
+        for ch in iter {
+            assert!(ch != NUL_16, "null character in CString16");
+            self.push_char16(ch);
+        }
+    }
+}
+
 impl TryFrom<&str> for CString16 {
     type Error = FromStrError;
 
@@ -56,6 +204,14 @@ impl TryFrom<&str> for CString16 {
     }
 }
 
+impl FromStr for CString16 {
+    type Err = FromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(s)
+    }
+}
+
 impl ops::Deref for CString16 {
     type Target = CStr16;
 
@@ -70,6 +226,31 @@ impl AsRef<CStr16> for CString16 {
     }
 }
 
+// `Borrow` requires `CString16` to be hashed and ordered like `CStr16`.
+impl Borrow<CStr16> for CString16 {
+    fn borrow(&self) -> &CStr16 {
+        self
+    }
+}
+
+impl Hash for CString16 {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_ref().hash(state)
+    }
+}
+
+impl PartialOrd for CString16 {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for CString16 {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_ref().cmp(other.as_ref())
+    }
+}
+
 impl fmt::Display for CString16 {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         self.as_ref().fmt(f)
@@ -78,14 +259,13 @@ impl fmt::Display for CString16 {
 
 impl PartialEq<&CStr16> for CString16 {
     fn eq(&self, other: &&CStr16) -> bool {
-        PartialEq::eq(self.as_ref(), other)
+        PartialEq::eq(self.as_ref(), *other)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::alloc_api::vec;
 
     #[test]
     fn test_cstring16_from_str() {
@@ -99,6 +279,92 @@ mod tests {
         assert_eq!(CString16::try_from("x\0"), Err(FromStrError::InteriorNul));
     }
 
+    #[test]
+    fn test_cstring16_push() {
+        let mut s = CString16::default();
+        assert_eq!(s, CString16::try_from("").unwrap());
+
+        s.push('a').unwrap();
+        s.push_str("bc").unwrap();
+        assert_eq!(s, CString16::try_from("abc").unwrap());
+
+        let mut buf = [0; 3];
+        s.push_cstr16(CStr16::from_str_with_buf("de", &mut buf).unwrap());
+        assert_eq!(s, CString16::try_from("abcde").unwrap());
+        assert_eq!(s.to_u16_slice_with_nul().last(), Some(&0));
+
+        assert_eq!("ab".parse(), Ok(CString16::try_from("ab").unwrap()));
+    }
+
+    #[test]
+    fn test_cstring16_push_invalid() {
+        let mut s = CString16::try_from("abc").unwrap();
+
+        assert_eq!(s.push('😀'), Err(FromStrError::InvalidChar));
+        assert_eq!(s.push('\0'), Err(FromStrError::InteriorNul));
+        // Nothing is appended if any character is invalid.
+        assert_eq!(s.push_str("de😀"), Err(FromStrError::InvalidChar));
+        assert_eq!(s.push_str("d\0e"), Err(FromStrError::InteriorNul));
+        assert_eq!(s, CString16::try_from("abc").unwrap());
+
+        assert_eq!(CString16::from_str("😀"), Err(FromStrError::InvalidChar));
+    }
+
+    #[test]
+    fn test_cstring16_collect() {
+        let s: CString16 = "abc".chars().collect();
+        assert_eq!(s, CString16::try_from("abc").unwrap());
+
+        let s: CString16 = s
+            .iter()
+            .copied()
+            .filter(|&c| char::from(c) != 'b')
+            .collect();
+        assert_eq!(s, CString16::try_from("ac").unwrap());
+
+        let mut s: CString16 = core::iter::empty::<char>().collect();
+        assert_eq!(s, CString16::default());
+        s.extend("de".chars());
+        assert_eq!(s, CString16::try_from("de").unwrap());
+        assert_eq!(s.to_u16_slice_with_nul().last(), Some(&0));
+
+        assert_eq!(
+            CString16::try_from_iter("xy".chars()),
+            Ok(CString16::try_from("xy").unwrap())
+        );
+        assert_eq!(
+            CString16::try_from_iter("x😀".chars()),
+            Err(FromStrError::InvalidChar)
+        );
+        assert_eq!(
+            CString16::try_from_iter("x\0".chars()),
+            Err(FromStrError::InteriorNul)
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_cstring16_collect_invalid() {
+        let _: CString16 = "😀".chars().collect();
+    }
+
+    /// Test looking up `CString16` keys with a `&CStr16`.
+    #[test]
+    fn test_cstring16_borrow() {
+        extern crate alloc;
+        use alloc::collections::BTreeMap;
+
+        let mut map = BTreeMap::new();
+        map.insert(CString16::try_from("BootOrder").unwrap(), 1);
+        map.insert(CString16::try_from("Boot0000").unwrap(), 2);
+
+        let mut buf = [0; 10];
+        let key = CStr16::from_str_with_buf("BootOrder", &mut buf).unwrap();
+        assert_eq!(map.get(key), Some(&1));
+        let key = CStr16::from_str_with_buf("Boot0001", &mut buf).unwrap();
+        assert_eq!(map.get(key), None);
+    }
+
     /// Test `CString16 == &CStr16` and `&CStr16 == CString16`.
     #[test]
     fn test_cstring16_cstr16_eq() {
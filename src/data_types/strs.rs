@@ -1,7 +1,9 @@
 use super::chars::{Char16, Char8, NUL_16, NUL_8};
 #[cfg(feature = "exts")]
 use super::CString16;
+use core::cmp::Ordering;
 use core::fmt;
+use core::hash::{Hash, Hasher};
 use core::iter::Iterator;
 use core::result::Result;
 use core::slice;
@@ -19,7 +21,8 @@ pub enum FromSliceWithNulError {
     NotNulTerminated,
 }
 
-/// Error returned by [`CStr16::from_str_with_buf`].
+/// Error returned by [`CStr16::from_str_with_buf`], [`CStr8::from_str_with_buf`],
+/// [`CStr8::to_cstr16_with_buf`] and [`CStr16::to_cstr8_with_buf`].
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum FromStrWithBufError {
     /// An invalid character was encountered before the end of the string
@@ -33,10 +36,35 @@ pub enum FromStrWithBufError {
     BufferTooSmall,
 }
 
+impl fmt::Display for FromSliceWithNulError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidChar(pos) => write!(f, "invalid character at index {}", pos),
+            Self::InteriorNul(pos) => write!(f, "null character at index {}", pos),
+            Self::NotNulTerminated => f.write_str("string is not null-terminated"),
+        }
+    }
+}
+
+impl core::error::Error for FromSliceWithNulError {}
+
+impl fmt::Display for FromStrWithBufError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidChar(pos) => write!(f, "invalid character at index {}", pos),
+            Self::InteriorNul(pos) => write!(f, "null character at index {}", pos),
+            Self::BufferTooSmall => f.write_str("buffer is too small for the string"),
+        }
+    }
+}
+
+impl core::error::Error for FromStrWithBufError {}
+
 /// A Latin-1 null-terminated string
 ///
 /// This type is largely inspired by `std::ffi::CStr`, see the documentation of
 /// `CStr` for more details on its semantics.
+#[derive(Eq, PartialEq)]
 #[repr(transparent)]
 pub struct CStr8([Char8]);
 
@@ -80,11 +108,87 @@ impl CStr8 {
         &*(chars as *const [u8] as *const Self)
     }
 
+    /// Convert a [`&str`] to a `&CStr8`, backed by a buffer.
+    ///
+    /// The input string must contain only Latin-1 characters (up to U+00FF),
+    /// and must not contain any null characters (even at the end of the
+    /// input).
+    ///
+    /// The backing buffer must be big enough to hold the converted string as
+    /// well as a trailing null character.
+    ///
+    /// # Examples
+    ///
+    /// Convert the UTF-8 string "ABC" to a `&CStr8`:
+    ///
+    /// ```
+    /// use uefi::CStr8;
+    ///
+    /// let mut buf = [0; 4];
+    /// CStr8::from_str_with_buf("ABC", &mut buf).unwrap();
+    /// ```
+    pub fn from_str_with_buf<'a>(
+        input: &str,
+        buf: &'a mut [u8],
+    ) -> Result<&'a Self, FromStrWithBufError> {
+        let mut index = 0;
+
+        for c in input.chars() {
+            let c = u8::try_from(c).map_err(|_| FromStrWithBufError::InvalidChar(index))?;
+            if c == 0 {
+                return Err(FromStrWithBufError::InteriorNul(index));
+            }
+            *buf.get_mut(index)
+                .ok_or(FromStrWithBufError::BufferTooSmall)? = c;
+            index += 1;
+        }
+
+        // Add trailing null character.
+        *buf.get_mut(index)
+            .ok_or(FromStrWithBufError::BufferTooSmall)? = 0;
+
+        // Every character was checked above, and the only null is the
+        // trailing one.
+        Ok(unsafe { Self::from_bytes_with_nul_unchecked(&buf[..index + 1]) })
+    }
+
     /// Returns the inner pointer to this C string
     pub fn as_ptr(&self) -> *const Char8 {
         self.0.as_ptr()
     }
 
+    /// Returns an iterator over this C string
+    pub fn iter(&self) -> CStr8Iter<'_> {
+        CStr8Iter {
+            inner: self,
+            pos: 0,
+        }
+    }
+
+    /// Convert this Latin-1 string to a `&CStr16`, backed by a buffer.
+    ///
+    /// Each Latin-1 character is widened to the UCS-2 code point with the
+    /// same value. The backing buffer must be big enough to hold the
+    /// converted string as well as a trailing null character, otherwise
+    /// [`FromStrWithBufError::BufferTooSmall`] is returned.
+    pub fn to_cstr16_with_buf<'a>(
+        &self,
+        buf: &'a mut [u16],
+    ) -> Result<&'a CStr16, FromStrWithBufError> {
+        let chars = self.to_bytes_with_nul();
+        let buf = buf
+            .get_mut(..chars.len())
+            .ok_or(FromStrWithBufError::BufferTooSmall)?;
+
+        for (dst, &src) in buf.iter_mut().zip(chars) {
+            *dst = src.into();
+        }
+
+        // Every Latin-1 character is a valid UCS-2 code point, and the only
+        // null is the trailing one copied from `self`.
+        Ok(unsafe { CStr16::from_u16_with_nul_unchecked(buf) })
+    }
+
     /// Converts this C string to a slice of bytes
     pub fn to_bytes(&self) -> &[u8] {
         let chars = self.to_bytes_with_nul();
@@ -151,7 +255,7 @@ impl CStr16 {
     ///
     /// It's the callers responsability to ensure chars is a valid UCS-2
     /// null-terminated string, with no interior null bytes.
-    pub unsafe fn from_u16_with_nul_unchecked(codes: &[u16]) -> &Self {
+    pub const unsafe fn from_u16_with_nul_unchecked(codes: &[u16]) -> &Self {
         &*(codes as *const [u16] as *const Self)
     }
 
@@ -178,6 +282,31 @@ impl CStr16 {
         input: &str,
         buf: &'a mut [u16],
     ) -> Result<&'a Self, FromStrWithBufError> {
+        Self::from_str_with_buf_len(input, buf).map(|(s, _)| s)
+    }
+
+    /// Convert a [`&str`] to a `&CStr16`, backed by a buffer, and also
+    /// return the number of `u16` code units written to the buffer.
+    ///
+    /// This behaves like [`CStr16::from_str_with_buf`], but the returned
+    /// length (which includes the trailing null character) makes it possible
+    /// to pack several strings back-to-back into the same buffer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uefi::CStr16;
+    ///
+    /// let mut buf = [0; 8];
+    /// let (_, len) = CStr16::from_str_with_buf_len("AB", &mut buf).unwrap();
+    /// assert_eq!(len, 3);
+    /// let (s, _) = CStr16::from_str_with_buf_len("CD", &mut buf[len..]).unwrap();
+    /// assert_eq!(s.to_u16_slice(), [67, 68]);
+    /// ```
+    pub fn from_str_with_buf_len<'a>(
+        input: &str,
+        buf: &'a mut [u16],
+    ) -> Result<(&'a Self, usize), FromStrWithBufError> {
         let mut index = 0;
 
         // Convert to UTF-16.
@@ -194,11 +323,37 @@ impl CStr16 {
         // Convert from u16 to Char16. This checks for invalid UCS-2 chars and
         // interior nulls. The NotNulTerminated case is unreachable because we
         // just added a trailing null character.
-        Self::from_u16_with_nul(&buf[..index + 1]).map_err(|err| match err {
-            FromSliceWithNulError::InvalidChar(p) => FromStrWithBufError::InvalidChar(p),
-            FromSliceWithNulError::InteriorNul(p) => FromStrWithBufError::InteriorNul(p),
-            FromSliceWithNulError::NotNulTerminated => unreachable!(),
-        })
+        let len = index + 1;
+        Self::from_u16_with_nul(&buf[..len])
+            .map(|s| (s, len))
+            .map_err(|err| match err {
+                FromSliceWithNulError::InvalidChar(p) => FromStrWithBufError::InvalidChar(p),
+                FromSliceWithNulError::InteriorNul(p) => FromStrWithBufError::InteriorNul(p),
+                FromSliceWithNulError::NotNulTerminated => unreachable!(),
+            })
+    }
+
+    /// Convert this UCS-2 string to a Latin-1 `&CStr8`, backed by a buffer.
+    ///
+    /// Returns [`FromStrWithBufError::InvalidChar`] if a character is
+    /// outside the Latin-1 range (greater than 0xFF), and
+    /// [`FromStrWithBufError::BufferTooSmall`] if the buffer cannot hold the
+    /// converted string as well as a trailing null character.
+    pub fn to_cstr8_with_buf<'a>(
+        &self,
+        buf: &'a mut [u8],
+    ) -> Result<&'a CStr8, FromStrWithBufError> {
+        let codes = self.to_u16_slice_with_nul();
+        let buf = buf
+            .get_mut(..codes.len())
+            .ok_or(FromStrWithBufError::BufferTooSmall)?;
+
+        for (pos, (dst, &src)) in buf.iter_mut().zip(codes).enumerate() {
+            *dst = u8::try_from(src).map_err(|_| FromStrWithBufError::InvalidChar(pos))?;
+        }
+
+        // The only null is the trailing one copied from `self`.
+        Ok(unsafe { CStr8::from_bytes_with_nul_unchecked(buf) })
     }
 
     /// Returns the inner pointer to this C string
@@ -206,11 +361,34 @@ impl CStr16 {
         self.0.as_ptr()
     }
 
+    /// Get the underlying [`Char16`] slice, excluding the trailing null.
+    pub fn as_slice(&self) -> &[Char16] {
+        &self.0[..self.len()]
+    }
+
     /// Get the underlying [`Char16`] slice, including the trailing null.
     pub fn as_slice_with_nul(&self) -> &[Char16] {
         &self.0
     }
 
+    /// Get the character at `index`, or `None` if `index` is out of bounds.
+    ///
+    /// The trailing null is not part of the string, so `get(self.len())`
+    /// returns `None`.
+    pub fn get(&self, index: usize) -> Option<Char16> {
+        self.as_slice().get(index).copied()
+    }
+
+    /// Get the character at `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds, like [`CStr16::get`] returning
+    /// `None`.
+    pub fn char_at(&self, index: usize) -> Char16 {
+        self.as_slice()[index]
+    }
+
     /// Converts this C string to a u16 slice
     pub fn to_u16_slice(&self) -> &[u16] {
         let chars = self.to_u16_slice_with_nul();
@@ -222,11 +400,91 @@ impl CStr16 {
         unsafe { &*(&self.0 as *const [Char16] as *const [u16]) }
     }
 
+    /// Get the raw bytes of this C string, excluding the trailing null.
+    ///
+    /// See [`CStr16::as_bytes_with_nul`] for the byte order.
+    pub fn as_bytes(&self) -> &[u8] {
+        let bytes = self.as_bytes_with_nul();
+        &bytes[..bytes.len() - 2]
+    }
+
+    /// Get the raw bytes of this C string, including the trailing null.
+    ///
+    /// Characters are stored as native `u16`s, so each one is encoded in the
+    /// byte order of the target. All UEFI targets are little-endian, which is
+    /// the byte order of UCS-2 strings in files and on the wire.
+    pub fn as_bytes_with_nul(&self) -> &[u8] {
+        unsafe { slice::from_raw_parts(self.0.as_ptr() as *const u8, self.num_bytes()) }
+    }
+
     /// Returns an iterator over this C string
     pub fn iter(&self) -> CStr16Iter {
         CStr16Iter {
             inner: self,
             pos: 0,
+            end: self.len(),
+        }
+    }
+
+    /// Get the number of characters in the string (excluding the trailing
+    /// null character).
+    pub const fn len(&self) -> usize {
+        self.0.len() - 1
+    }
+
+    /// Returns `true` if the string contains no characters (other than the
+    /// trailing null character).
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns `true` if `prefix` is a prefix of this string.
+    pub fn starts_with(&self, prefix: &CStr16) -> bool {
+        self.as_slice().starts_with(prefix.as_slice())
+    }
+
+    /// Returns `true` if `suffix` is a suffix of this string.
+    pub fn ends_with(&self, suffix: &CStr16) -> bool {
+        self.as_slice().ends_with(suffix.as_slice())
+    }
+
+    /// Returns `true` if `needle` occurs anywhere in this string.
+    ///
+    /// An empty `needle` is contained in every string.
+    pub fn contains(&self, needle: &CStr16) -> bool {
+        let needle = needle.as_slice();
+        needle.is_empty()
+            || self
+                .as_slice()
+                .windows(needle.len())
+                .any(|window| window == needle)
+    }
+
+    /// Checks that two strings are an ASCII case-insensitive match.
+    ///
+    /// Only the ASCII letters `A`-`Z` and `a`-`z` are folded; all other code
+    /// points must match exactly. This matches how names are compared on
+    /// case-insensitive file systems such as FAT.
+    pub fn eq_ignore_ascii_case(&self, other: &CStr16) -> bool {
+        fn fold(c: u16) -> u16 {
+            match c {
+                0x41..=0x5a => c + 0x20,
+                _ => c,
+            }
+        }
+
+        let (a, b) = (self.to_u16_slice(), other.to_u16_slice());
+        a.len() == b.len() && a.iter().zip(b).all(|(&a, &b)| fold(a) == fold(b))
+    }
+
+    /// Returns an iterator over the subslices of this string separated by
+    /// `sep`, excluding the trailing null.
+    ///
+    /// Like [`str::split`], consecutive separators produce empty subslices.
+    pub fn split(&self, sep: Char16) -> CStr16Split<'_> {
+        CStr16Split {
+            remaining: Some(self.as_slice()),
+            sep,
         }
     }
 
@@ -250,7 +508,13 @@ impl CStr16 {
     /// log::info!("as rust str: {}", buf.as_str());
     /// ```
     ///
+    /// If an allocator is available, the [`Display`] implementation of
+    /// `CStr16` makes [`ToString::to_string`] available, which allocates and
+    /// returns the decoded string directly.
+    ///
     /// [`alloc::string::String`]: https://doc.rust-lang.org/nightly/alloc/string/struct.String.html
+    /// [`Display`]: fmt::Display
+    /// [`ToString::to_string`]: https://doc.rust-lang.org/nightly/alloc/string/trait.ToString.html#tymethod.to_string
     pub fn as_str_in_buf(&self, buf: &mut dyn core::fmt::Write) -> core::fmt::Result {
         for c16 in self.iter() {
             buf.write_char(char::from(*c16))?;
@@ -259,24 +523,113 @@ impl CStr16 {
     }
 }
 
+/// An iterator over `CStr8`.
+#[derive(Debug)]
+pub struct CStr8Iter<'a> {
+    inner: &'a CStr8,
+    pos: usize,
+}
+
+impl<'a> Iterator for CStr8Iter<'a> {
+    type Item = &'a Char8;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.pos >= self.inner.0.len() - 1 {
+            None
+        } else {
+            self.pos += 1;
+            self.inner.0.get(self.pos - 1)
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.inner.0.len() - 1 - self.pos;
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for CStr8Iter<'_> {}
+
 /// An iterator over `CStr16`.
 #[derive(Debug)]
 pub struct CStr16Iter<'a> {
     inner: &'a CStr16,
     pos: usize,
+    // Index one past the last character yet to be yielded from the back.
+    end: usize,
 }
 
 impl<'a> Iterator for CStr16Iter<'a> {
     type Item = &'a Char16;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.pos >= self.inner.0.len() - 1 {
+        if self.pos >= self.end {
             None
         } else {
             self.pos += 1;
             self.inner.0.get(self.pos - 1)
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.end - self.pos;
+        (len, Some(len))
+    }
+}
+
+impl DoubleEndedIterator for CStr16Iter<'_> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.pos >= self.end {
+            None
+        } else {
+            self.end -= 1;
+            self.inner.0.get(self.end)
+        }
+    }
+}
+
+impl ExactSizeIterator for CStr16Iter<'_> {}
+
+/// An iterator over the subslices of a `CStr16` separated by a [`Char16`].
+///
+/// Returned by [`CStr16::split`].
+#[derive(Debug)]
+pub struct CStr16Split<'a> {
+    remaining: Option<&'a [Char16]>,
+    sep: Char16,
+}
+
+impl<'a> Iterator for CStr16Split<'a> {
+    type Item = &'a [Char16];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let remaining = self.remaining?;
+        match remaining.iter().position(|&c| c == self.sep) {
+            Some(pos) => {
+                self.remaining = Some(&remaining[pos + 1..]);
+                Some(&remaining[..pos])
+            }
+            None => {
+                self.remaining = None;
+                Some(remaining)
+            }
+        }
+    }
+}
+
+impl fmt::Debug for CStr8 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "CStr8({:?})", &self.0)
+    }
+}
+
+impl fmt::Display for CStr8 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for c in self.iter() {
+            <Char8 as fmt::Display>::fmt(c, f)?;
+        }
+        Ok(())
+    }
 }
 
 impl fmt::Debug for CStr16 {
@@ -294,6 +647,46 @@ impl fmt::Display for CStr16 {
     }
 }
 
+impl Hash for CStr8 {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.to_bytes().hash(state)
+    }
+}
+
+impl Hash for CStr16 {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_slice().hash(state)
+    }
+}
+
+impl PartialOrd for CStr16 {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for CStr16 {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_slice().cmp(other.as_slice())
+    }
+}
+
+/// Compares the characters of a `CStr16` with those of a `str`.
+///
+/// Characters outside the Basic Multilingual Plane, which UCS-2 cannot
+/// represent, never compare equal.
+impl PartialEq<str> for CStr16 {
+    fn eq(&self, other: &str) -> bool {
+        self.iter().map(|&c| char::from(c)).eq(other.chars())
+    }
+}
+
+impl PartialEq<&str> for CStr16 {
+    fn eq(&self, other: &&str) -> bool {
+        PartialEq::<str>::eq(self, other)
+    }
+}
+
 #[cfg(feature = "exts")]
 impl PartialEq<CString16> for &CStr16 {
     fn eq(&self, other: &CString16) -> bool {
@@ -304,6 +697,7 @@ impl PartialEq<CString16> for &CStr16 {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use uefi::cstr16;
 
     #[test]
     fn test_cstr16_num_bytes() {
@@ -311,6 +705,368 @@ mod tests {
         assert_eq!(s.num_bytes(), 8);
     }
 
+    #[test]
+    fn test_cstr16_as_bytes() {
+        let s = CStr16::from_u16_with_nul(&[0x41, 0x42, 0]).unwrap();
+        assert_eq!(s.as_bytes_with_nul(), [0x41, 0x00, 0x42, 0x00, 0x00, 0x00]);
+        assert_eq!(s.as_bytes(), [0x41, 0x00, 0x42, 0x00]);
+
+        let s = CStr16::from_u16_with_nul(&[0x20ac, 0]).unwrap();
+        assert_eq!(s.as_bytes(), [0xac, 0x20]);
+
+        assert_eq!(cstr16!("").as_bytes(), []);
+    }
+
+    #[test]
+    fn test_cstr16_eq_str() {
+        let s = cstr16!("test_name");
+        assert_eq!(*s, "test_name");
+        assert_eq!(*s, *"test_name");
+        assert_ne!(*s, "test_nam");
+        assert_ne!(*s, "test_name2");
+        assert_ne!(*s, "Test_name");
+
+        assert_eq!(*cstr16!(""), "");
+        assert_eq!(*cstr16!("rÉsumé €"), "rÉsumé €");
+
+        // Characters outside of the BMP cannot be represented in UCS-2.
+        let mut buf = [0; 4];
+        let s = CStr16::from_u16_with_nul(&[0x61, 0xd83d, 0xde00, 0]);
+        assert!(s.is_err());
+        let s = CStr16::from_str_with_buf("a", &mut buf).unwrap();
+        assert_ne!(*s, "a😀");
+    }
+
+    #[test]
+    fn test_cstr16_get() {
+        let s = cstr16!("C:");
+        let [c, colon] = ['C', ':'].map(|c| Char16::try_from(c).unwrap());
+
+        assert_eq!(s.get(0), Some(c));
+        assert_eq!(s.get(1), Some(colon));
+        assert_eq!(s.get(2), None);
+        assert_eq!(s.get(usize::MAX), None);
+        assert_eq!(cstr16!("").get(0), None);
+
+        assert_eq!(s.char_at(0), c);
+        assert_eq!(s.char_at(1), colon);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_cstr16_char_at_out_of_bounds() {
+        let _ = cstr16!("C:").char_at(2);
+    }
+
+    #[test]
+    fn test_error_display() {
+        extern crate std;
+        use std::string::ToString;
+
+        assert_eq!(
+            FromSliceWithNulError::InvalidChar(3).to_string(),
+            "invalid character at index 3"
+        );
+        assert_eq!(
+            FromSliceWithNulError::InteriorNul(1).to_string(),
+            "null character at index 1"
+        );
+        assert_eq!(
+            FromSliceWithNulError::NotNulTerminated.to_string(),
+            "string is not null-terminated"
+        );
+
+        assert_eq!(
+            FromStrWithBufError::InvalidChar(3).to_string(),
+            "invalid character at index 3"
+        );
+        assert_eq!(
+            FromStrWithBufError::InteriorNul(1).to_string(),
+            "null character at index 1"
+        );
+        assert_eq!(
+            FromStrWithBufError::BufferTooSmall.to_string(),
+            "buffer is too small for the string"
+        );
+    }
+
+    #[test]
+    fn test_cstr16_len() {
+        let s = CStr16::from_u16_with_nul(&[65, 66, 67, 0]).unwrap();
+        assert_eq!(s.len(), 3);
+        assert!(!s.is_empty());
+
+        let s = CStr16::from_u16_with_nul(&[0]).unwrap();
+        assert_eq!(s.len(), 0);
+        assert!(s.is_empty());
+    }
+
+    #[test]
+    fn test_cstr16_ord() {
+        let mut buf1 = [0; 4];
+        let mut buf2 = [0; 4];
+
+        // A prefix orders before the longer string.
+        let ab = CStr16::from_str_with_buf("AB", &mut buf1).unwrap();
+        let abc = CStr16::from_str_with_buf("ABC", &mut buf2).unwrap();
+        assert!(ab < abc);
+        assert_eq!(abc.cmp(ab), Ordering::Greater);
+
+        // Equal strings compare equal, regardless of the backing buffer.
+        let mut buf3 = [0; 8];
+        let abc2 = CStr16::from_str_with_buf("ABC", &mut buf3).unwrap();
+        assert_eq!(abc, abc2);
+        assert_eq!(abc.cmp(abc2), Ordering::Equal);
+
+        // Comparison is case sensitive: uppercase ASCII orders first.
+        let mut buf4 = [0; 4];
+        let lower = CStr16::from_str_with_buf("abc", &mut buf4).unwrap();
+        assert!(abc < lower);
+    }
+
+    #[test]
+    fn test_cstr_hash() {
+        extern crate std;
+        use std::collections::HashSet;
+
+        let mut set = HashSet::new();
+        let mut buf1 = [0; 4];
+        let mut buf2 = [0; 8];
+        set.insert(CStr16::from_str_with_buf("ABC", &mut buf1).unwrap());
+        set.insert(CStr16::from_str_with_buf("ABC", &mut buf2).unwrap());
+        assert_eq!(set.len(), 1);
+
+        let mut set = HashSet::new();
+        let bytes1 = [65, 66, 67, 0];
+        let bytes2 = [65, 66, 67, 0];
+        set.insert(CStr8::from_bytes_with_nul(&bytes1).unwrap());
+        set.insert(CStr8::from_bytes_with_nul(&bytes2).unwrap());
+        assert_eq!(set.len(), 1);
+    }
+
+    #[test]
+    fn test_cstr16_substrings() {
+        let mut buf = [0; 10];
+        let mut prefix_buf = [0; 5];
+        let mut suffix_buf = [0; 6];
+        let mut middle_buf = [0; 4];
+        let mut empty_buf = [0; 1];
+        let s = CStr16::from_str_with_buf("BootOrder", &mut buf).unwrap();
+        let prefix = CStr16::from_str_with_buf("Boot", &mut prefix_buf).unwrap();
+        let suffix = CStr16::from_str_with_buf("Order", &mut suffix_buf).unwrap();
+        let middle = CStr16::from_str_with_buf("tOr", &mut middle_buf).unwrap();
+        let empty = CStr16::from_str_with_buf("", &mut empty_buf).unwrap();
+
+        assert!(s.starts_with(prefix));
+        assert!(!s.starts_with(suffix));
+        assert!(s.ends_with(suffix));
+        assert!(!s.ends_with(prefix));
+        assert!(s.contains(middle));
+        assert!(!prefix.contains(middle));
+        assert!(!prefix.contains(s));
+
+        // Empty needles always match.
+        assert!(s.starts_with(empty));
+        assert!(s.ends_with(empty));
+        assert!(s.contains(empty));
+        assert!(empty.contains(empty));
+    }
+
+    #[test]
+    fn test_cstr16_eq_ignore_ascii_case() {
+        let mut buf1 = [0; 16];
+        let mut buf2 = [0; 16];
+
+        let a = CStr16::from_str_with_buf("README.TXT", &mut buf1).unwrap();
+        let b = CStr16::from_str_with_buf("readme.txt", &mut buf2).unwrap();
+        assert!(a.eq_ignore_ascii_case(b));
+
+        // Only the ASCII part is folded.
+        let a = CStr16::from_str_with_buf("rÉsumé", &mut buf1).unwrap();
+        let b = CStr16::from_str_with_buf("rÉSUMÉ", &mut buf2).unwrap();
+        assert!(!a.eq_ignore_ascii_case(b));
+        let a = CStr16::from_str_with_buf("rÉsumé", &mut buf1).unwrap();
+        let b = CStr16::from_str_with_buf("RÉSUMé", &mut buf2).unwrap();
+        assert!(a.eq_ignore_ascii_case(b));
+
+        // Different lengths never match.
+        let a = CStr16::from_str_with_buf("abc", &mut buf1).unwrap();
+        let b = CStr16::from_str_with_buf("ABCD", &mut buf2).unwrap();
+        assert!(!a.eq_ignore_ascii_case(b));
+    }
+
+    #[test]
+    fn test_cstr16_split() {
+        let mut buf = [0; 16];
+        let sep = Char16::try_from('\\').unwrap();
+
+        let s = CStr16::from_str_with_buf("EFI\\\\Boot\\", &mut buf).unwrap();
+        let parts: [&[u16]; 4] = [&[69, 70, 73], &[], &[66, 111, 111, 116], &[]];
+        let mut split = s.split(sep);
+        for part in parts {
+            let next = split.next().unwrap();
+            assert!(next.iter().map(|&c| u16::from(c)).eq(part.iter().copied()));
+        }
+        assert!(split.next().is_none());
+
+        // An empty string yields a single empty subslice.
+        let s = CStr16::from_str_with_buf("", &mut buf).unwrap();
+        let mut split = s.split(sep);
+        assert_eq!(split.next(), Some(&[][..]));
+        assert!(split.next().is_none());
+    }
+
+    #[test]
+    fn test_cstr8_display() {
+        extern crate std;
+        use std::string::ToString;
+
+        let s = CStr8::from_bytes_with_nul(&[0x63, 0x61, 0x66, 0xe9, 0]).unwrap();
+        assert_eq!(s.to_string(), "café");
+    }
+
+    #[test]
+    fn test_cstr8_iter() {
+        let s = CStr8::from_bytes_with_nul(&[65, 66, 67, 0]).unwrap();
+
+        let mut iter = s.iter();
+        assert_eq!(iter.len(), 3);
+        assert_eq!(iter.next(), Some(&Char8::from(65)));
+        assert_eq!(iter.len(), 2);
+        assert_eq!(iter.by_ref().count(), 2);
+        assert_eq!(iter.next(), None);
+
+        // The trailing null is never yielded.
+        assert!(s.iter().all(|&c| c != NUL_8));
+    }
+
+    #[test]
+    fn test_cstr8_from_str_with_buf() {
+        let mut buf = [0; 4];
+
+        // OK: buf is exactly the right size.
+        let s = CStr8::from_str_with_buf("ABC", &mut buf).unwrap();
+        assert_eq!(s.to_bytes_with_nul(), [65, 66, 67, 0]);
+
+        // OK: buf is bigger than needed.
+        let s = CStr8::from_str_with_buf("A", &mut buf).unwrap();
+        assert_eq!(s.to_bytes_with_nul(), [65, 0]);
+
+        // OK: Latin-1 characters beyond ASCII.
+        let s = CStr8::from_str_with_buf("rÿ", &mut buf).unwrap();
+        assert_eq!(s.to_bytes_with_nul(), [0x72, 0xff, 0]);
+
+        // Error: buf is too small.
+        assert_eq!(
+            CStr8::from_str_with_buf("ABCD", &mut buf).unwrap_err(),
+            FromStrWithBufError::BufferTooSmall
+        );
+
+        // Error: invalid character.
+        assert_eq!(
+            CStr8::from_str_with_buf("a😀", &mut buf).unwrap_err(),
+            FromStrWithBufError::InvalidChar(1),
+        );
+        assert_eq!(
+            CStr8::from_str_with_buf("aĀ", &mut buf).unwrap_err(),
+            FromStrWithBufError::InvalidChar(1),
+        );
+
+        // Error: interior null.
+        assert_eq!(
+            CStr8::from_str_with_buf("a\0b", &mut buf).unwrap_err(),
+            FromStrWithBufError::InteriorNul(1),
+        );
+    }
+
+    #[test]
+    fn test_cstr8_to_cstr16() {
+        let s = CStr8::from_bytes_with_nul(&[0x41, 0xe9, 0]).unwrap();
+
+        let mut buf = [0; 3];
+        let s16 = s.to_cstr16_with_buf(&mut buf).unwrap();
+        assert_eq!(s16.to_u16_slice_with_nul(), [0x41, 0xe9, 0]);
+
+        let mut buf = [0; 2];
+        assert_eq!(
+            s.to_cstr16_with_buf(&mut buf).unwrap_err(),
+            FromStrWithBufError::BufferTooSmall
+        );
+    }
+
+    #[test]
+    fn test_cstr16_to_cstr8() {
+        let s = CStr16::from_u16_with_nul(&[0x41, 0xe9, 0]).unwrap();
+
+        let mut buf = [0; 3];
+        let s8 = s.to_cstr8_with_buf(&mut buf).unwrap();
+        assert_eq!(s8.to_bytes_with_nul(), [0x41, 0xe9, 0]);
+
+        let mut buf = [0; 2];
+        assert_eq!(
+            s.to_cstr8_with_buf(&mut buf).unwrap_err(),
+            FromStrWithBufError::BufferTooSmall
+        );
+
+        // Characters outside of Latin-1 cannot be narrowed.
+        let s = CStr16::from_u16_with_nul(&[0x41, 0x100, 0]).unwrap();
+        let mut buf = [0; 3];
+        assert_eq!(
+            s.to_cstr8_with_buf(&mut buf).unwrap_err(),
+            FromStrWithBufError::InvalidChar(1)
+        );
+    }
+
+    #[test]
+    fn test_cstr16_to_string() {
+        extern crate std;
+        use std::string::ToString;
+
+        let mut buf = [0; 8];
+        let s = CStr16::from_str_with_buf("rÉsumé", &mut buf).unwrap();
+        assert_eq!(s.to_string(), "rÉsumé");
+    }
+
+    #[test]
+    fn test_cstr16_iter() {
+        let mut buf = [0; 4];
+        let s = CStr16::from_str_with_buf("ABC", &mut buf).unwrap();
+        let [a, b, c] = ['A', 'B', 'C'].map(|c| Char16::try_from(c).unwrap());
+
+        let mut iter = s.iter();
+        assert_eq!(iter.len(), 3);
+        assert_eq!(iter.next(), Some(&a));
+        assert_eq!(iter.next(), Some(&b));
+        assert_eq!(iter.next(), Some(&c));
+        assert_eq!(iter.len(), 0);
+        assert_eq!(iter.next(), None);
+
+        let mut iter = s.iter().rev();
+        assert_eq!(iter.next(), Some(&c));
+        assert_eq!(iter.next(), Some(&b));
+        assert_eq!(iter.next(), Some(&a));
+        assert_eq!(iter.next(), None);
+
+        // Iterating from both ends meets in the middle.
+        let mut iter = s.iter();
+        assert_eq!(iter.next_back(), Some(&c));
+        assert_eq!(iter.next(), Some(&a));
+        assert_eq!(iter.len(), 1);
+        assert_eq!(iter.next_back(), Some(&b));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+    }
+
+    #[test]
+    fn test_cstr16_macro() {
+        const S: &CStr16 = cstr16!("ABC");
+        assert_eq!(S.to_u16_slice_with_nul(), [65, 66, 67, 0]);
+
+        assert_eq!(cstr16!("").to_u16_slice_with_nul(), [0]);
+        assert_eq!(cstr16!("rÉ").to_u16_slice_with_nul(), [0x72, 0xc9, 0]);
+        assert_eq!(cstr16!("EFI\\Boot").len(), 8);
+    }
+
     #[test]
     fn test_cstr16_from_str_with_buf() {
         let mut buf = [0; 4];
@@ -341,4 +1097,31 @@ mod tests {
             FromStrWithBufError::InteriorNul(1),
         );
     }
+
+    #[test]
+    fn test_cstr16_from_str_with_buf_len() {
+        let mut buf = [0; 4];
+
+        // OK: empty input only writes the trailing null.
+        let (s, len) = CStr16::from_str_with_buf_len("", &mut buf).unwrap();
+        assert_eq!(s.to_u16_slice_with_nul(), [0]);
+        assert_eq!(len, 1);
+
+        // OK: buf is exactly the right size.
+        let (s, len) = CStr16::from_str_with_buf_len("ABC", &mut buf).unwrap();
+        assert_eq!(s.to_u16_slice_with_nul(), [65, 66, 67, 0]);
+        assert_eq!(len, 4);
+
+        // Error: buf is too small.
+        assert_eq!(
+            CStr16::from_str_with_buf_len("ABCD", &mut buf).unwrap_err(),
+            FromStrWithBufError::BufferTooSmall
+        );
+
+        // OK: pack two strings back-to-back.
+        let mut buf = [0; 6];
+        let (_, len) = CStr16::from_str_with_buf_len("AB", &mut buf).unwrap();
+        CStr16::from_str_with_buf_len("CD", &mut buf[len..]).unwrap();
+        assert_eq!(buf, [65, 66, 0, 67, 68, 0]);
+    }
 }
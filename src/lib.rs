@@ -27,6 +27,7 @@
 #![feature(auto_traits)]
 #![feature(control_flow_enum)]
 #![feature(try_trait_v2)]
+#![feature(try_trait_v2_residual)]
 #![feature(abi_efiapi)]
 #![feature(negative_impls)]
 #![no_std]
@@ -46,8 +47,8 @@ extern crate self as uefi;
 pub mod data_types;
 #[cfg(feature = "exts")]
 pub use self::data_types::CString16;
-pub use self::data_types::{unsafe_guid, Identify};
-pub use self::data_types::{CStr16, CStr8, Char16, Char8, Event, Guid, Handle};
+pub use self::data_types::{cstr16, guid, unsafe_guid, Identify};
+pub use self::data_types::{CStr16, CStr8, Char16, Char8, Event, Guid, Handle, PhysicalAddress};
 
 mod result;
 pub use self::result::{Completion, Result, ResultExt, Status};
@@ -66,3 +67,10 @@ pub mod exts;
 
 #[cfg(feature = "logger")]
 pub mod logger;
+
+// Items used by the code generated by the `uefi-macros` procedural macros,
+// which are not part of the public API.
+#[doc(hidden)]
+pub mod __private {
+    pub use crate::result::entry_result_status;
+}
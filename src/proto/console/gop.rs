@@ -55,7 +55,8 @@
 //! avoid tearing with animations.
 
 use crate::proto::Protocol;
-use crate::{unsafe_guid, Completion, Result, Status};
+use crate::result::Error;
+use crate::{unsafe_guid, Completion, PhysicalAddress, Result, Status};
 use core::marker::PhantomData;
 use core::mem;
 use core::ptr;
@@ -129,6 +130,9 @@ impl<'boot> GraphicsOutput<'boot> {
     /// Performs a blt (block transfer) operation on the frame buffer.
     ///
     /// Every operation requires different parameters.
+    ///
+    /// Returns an `INVALID_PARAMETER` error if a rectangle does not fit
+    /// within the current mode's resolution or within the provided buffer.
     pub fn blt(&mut self, op: BltOp) -> Result {
         // Demultiplex the operation type.
         unsafe {
@@ -138,7 +142,7 @@ impl<'boot> GraphicsOutput<'boot> {
                     dest: (dest_x, dest_y),
                     dims: (width, height),
                 } => {
-                    self.check_framebuffer_region((dest_x, dest_y), (width, height));
+                    self.check_framebuffer_region((dest_x, dest_y), (width, height))?;
                     (self.blt)(
                         self,
                         &color as *const _ as *mut _,
@@ -159,8 +163,8 @@ impl<'boot> GraphicsOutput<'boot> {
                     dest: dest_region,
                     dims: (width, height),
                 } => {
-                    self.check_framebuffer_region((src_x, src_y), (width, height));
-                    self.check_blt_buffer_region(dest_region, (width, height), buffer.len());
+                    self.check_framebuffer_region((src_x, src_y), (width, height))?;
+                    self.check_blt_buffer_region(dest_region, (width, height), buffer.len())?;
                     match dest_region {
                         BltRegion::Full => (self.blt)(
                             self,
@@ -199,8 +203,8 @@ impl<'boot> GraphicsOutput<'boot> {
                     dest: (dest_x, dest_y),
                     dims: (width, height),
                 } => {
-                    self.check_blt_buffer_region(src_region, (width, height), buffer.len());
-                    self.check_framebuffer_region((dest_x, dest_y), (width, height));
+                    self.check_blt_buffer_region(src_region, (width, height), buffer.len())?;
+                    self.check_framebuffer_region((dest_x, dest_y), (width, height))?;
                     match src_region {
                         BltRegion::Full => (self.blt)(
                             self,
@@ -238,8 +242,8 @@ impl<'boot> GraphicsOutput<'boot> {
                     dest: (dest_x, dest_y),
                     dims: (width, height),
                 } => {
-                    self.check_framebuffer_region((src_x, src_y), (width, height));
-                    self.check_framebuffer_region((dest_x, dest_y), (width, height));
+                    self.check_framebuffer_region((src_x, src_y), (width, height))?;
+                    self.check_framebuffer_region((dest_x, dest_y), (width, height))?;
                     (self.blt)(
                         self,
                         ptr::null_mut(),
@@ -259,39 +263,39 @@ impl<'boot> GraphicsOutput<'boot> {
     }
 
     /// Memory-safety check for accessing a region of the framebuffer
-    fn check_framebuffer_region(&self, coords: (usize, usize), dims: (usize, usize)) {
+    fn check_framebuffer_region(
+        &self,
+        coords: (usize, usize),
+        dims: (usize, usize),
+    ) -> core::result::Result<(), Error> {
         let (width, height) = self.current_mode_info().resolution();
-        assert!(
-            coords.0.saturating_add(dims.0) <= width,
-            "Horizontal framebuffer coordinate out of bounds"
-        );
-        assert!(
-            coords.1.saturating_add(dims.1) <= height,
-            "Vertical framebuffer coordinate out of bounds"
-        );
+        if coords.0.saturating_add(dims.0) > width || coords.1.saturating_add(dims.1) > height {
+            return Err(Status::INVALID_PARAMETER.into());
+        }
+        Ok(())
     }
 
     /// Memory-safety check for accessing a region of a user-provided buffer
-    fn check_blt_buffer_region(&self, region: BltRegion, dims: (usize, usize), buf_length: usize) {
-        match region {
-            BltRegion::Full => assert!(
-                dims.1.saturating_mul(dims.0) <= buf_length,
-                "BltBuffer access out of bounds"
-            ),
+    fn check_blt_buffer_region(
+        &self,
+        region: BltRegion,
+        dims: (usize, usize),
+        buf_length: usize,
+    ) -> core::result::Result<(), Error> {
+        let in_bounds = match region {
+            BltRegion::Full => dims.1.saturating_mul(dims.0) <= buf_length,
             BltRegion::SubRectangle {
                 coords: (x, y),
                 px_stride,
             } => {
-                assert!(
-                    x.saturating_add(dims.0) <= px_stride,
-                    "Horizontal BltBuffer coordinate out of bounds"
-                );
-                assert!(
-                    y.saturating_add(dims.1).saturating_mul(px_stride) <= buf_length,
-                    "Vertical BltBuffer coordinate out of bounds"
-                );
+                x.saturating_add(dims.0) <= px_stride
+                    && y.saturating_add(dims.1).saturating_mul(px_stride) <= buf_length
             }
+        };
+        if !in_bounds {
+            return Err(Status::INVALID_PARAMETER.into());
         }
+        Ok(())
     }
 
     /// Returns the frame buffer information for the current mode.
@@ -300,6 +304,15 @@ impl<'boot> GraphicsOutput<'boot> {
     }
 
     /// Access the frame buffer directly
+    ///
+    /// The layout of the pixels is given by the current mode's
+    /// [`ModeInfo::pixel_format`] and [`ModeInfo::stride`]: pixel `(x, y)`
+    /// starts at byte `4 * (y * stride + x)`. The frame buffer can't be
+    /// accessed in a [`PixelFormat::BltOnly`] mode.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the current mode is a [`PixelFormat::BltOnly`] mode.
     pub fn frame_buffer(&mut self) -> FrameBuffer {
         assert!(
             self.mode.info.format != PixelFormat::BltOnly,
@@ -314,6 +327,27 @@ impl<'boot> GraphicsOutput<'boot> {
             _lifetime: PhantomData,
         }
     }
+
+    /// Returns a snapshot of the frame buffer's location and layout in the
+    /// current mode.
+    ///
+    /// This gathers everything an OS loader needs to hand the frame buffer
+    /// over to the OS, and remains valid after boot services are exited as
+    /// long as the graphics mode is not changed.
+    pub fn frame_buffer_info(&self) -> FrameBufferInfo {
+        let info = self.mode.info;
+        let (width, height) = info.resolution();
+
+        FrameBufferInfo {
+            base: self.mode.fb_address,
+            size: self.mode.fb_size,
+            width,
+            height,
+            stride: info.stride(),
+            pixel_format: info.pixel_format(),
+            pixel_bitmask: info.pixel_bitmask(),
+        }
+    }
 }
 
 #[repr(C)]
@@ -368,6 +402,7 @@ pub struct PixelBitmask {
 }
 
 /// Represents a graphics mode compatible with a given graphics device.
+#[derive(Debug, Clone)]
 pub struct Mode {
     index: u32,
     info_sz: usize,
@@ -431,6 +466,28 @@ impl ModeInfo {
     }
 }
 
+/// Location and layout of the frame buffer in a given graphics mode.
+///
+/// Returned by [`GraphicsOutput::frame_buffer_info`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct FrameBufferInfo {
+    /// Physical address of the frame buffer.
+    pub base: PhysicalAddress,
+    /// Size of the frame buffer in bytes.
+    pub size: usize,
+    /// Horizontal resolution, in pixels.
+    pub width: usize,
+    /// Vertical resolution, in pixels.
+    pub height: usize,
+    /// Number of pixels per scanline.
+    pub stride: usize,
+    /// Format of the pixels in the frame buffer.
+    pub pixel_format: PixelFormat,
+    /// Bitmask of the custom pixel format, if `pixel_format` is
+    /// [`PixelFormat::Bitmask`].
+    pub pixel_bitmask: Option<PixelBitmask>,
+}
+
 /// Iterator for graphics modes.
 struct ModeIter<'gop> {
     gop: &'gop GraphicsOutput<'gop>,
@@ -589,49 +646,49 @@ impl<'gop> FrameBuffer<'gop> {
 
     /// Modify the i-th byte of the frame buffer
     ///
-    /// # Safety
+    /// The byte must be interpreted according to the pixel format and stride
+    /// specified by the mode info.
     ///
-    /// This operation is unsafe because...
-    /// - You must honor the pixel format and stride specified by the mode info
-    /// - There is no bound checking on memory accesses in release mode
+    /// # Panics
+    ///
+    /// Panics if `index` is out of the bounds of the frame buffer.
     #[inline]
-    pub unsafe fn write_byte(&mut self, index: usize, value: u8) {
-        debug_assert!(index < self.size, "Frame buffer accessed out of bounds");
-        self.base.add(index).write_volatile(value)
+    pub fn write_byte(&mut self, index: usize, value: u8) {
+        assert!(index < self.size, "Frame buffer accessed out of bounds");
+        unsafe { self.base.add(index).write_volatile(value) }
     }
 
     /// Read the i-th byte of the frame buffer
     ///
-    /// # Safety
+    /// The byte must be interpreted according to the pixel format and stride
+    /// specified by the mode info.
     ///
-    /// This operation is unsafe because...
-    /// - You must honor the pixel format and stride specified by the mode info
-    /// - There is no bound checking on memory accesses in release mode
+    /// # Panics
+    ///
+    /// Panics if `index` is out of the bounds of the frame buffer.
     #[inline]
-    pub unsafe fn read_byte(&self, index: usize) -> u8 {
-        debug_assert!(index < self.size, "Frame buffer accessed out of bounds");
-        self.base.add(index).read_volatile()
+    pub fn read_byte(&self, index: usize) -> u8 {
+        assert!(index < self.size, "Frame buffer accessed out of bounds");
+        unsafe { self.base.add(index).read_volatile() }
     }
 
-    /// Write a value in the frame buffer, starting at the i-th byte
+    /// Write bytes in the frame buffer, starting at the i-th byte
     ///
-    /// We only recommend using this method with [u8; N] arrays. Once Rust has
-    /// const generics, it will be deprecated and replaced with a write_bytes()
-    /// method that only accepts [u8; N] input.
+    /// The bytes must be laid out according to the pixel format and stride
+    /// specified by the mode info. For example, a pixel in the
+    /// [`PixelFormat::Bgr`] format is written as `[blue, green, red]`.
     ///
-    /// # Safety
+    /// # Panics
     ///
-    /// This operation is unsafe because...
-    /// - It is your reponsibility to make sure that the value type makes sense
-    /// - You must honor the pixel format and stride specified by the mode info
-    /// - There is no bound checking on memory accesses in release mode
+    /// Panics if the bytes do not fit in the frame buffer.
     #[inline]
-    pub unsafe fn write_value<T>(&mut self, index: usize, value: T) {
-        debug_assert!(
-            index.saturating_add(mem::size_of::<T>()) <= self.size,
+    pub fn write_value<const N: usize>(&mut self, index: usize, value: [u8; N]) {
+        assert!(
+            index.saturating_add(N) <= self.size,
             "Frame buffer accessed out of bounds"
         );
-        (self.base.add(index) as *mut T).write_volatile(value)
+        // Byte arrays have an alignment of 1, so any index is aligned.
+        unsafe { (self.base.add(index) as *mut [u8; N]).write_volatile(value) }
     }
 
     /// Read a value from the frame buffer, starting at the i-th byte
@@ -645,10 +702,13 @@ impl<'gop> FrameBuffer<'gop> {
     /// This operation is unsafe because...
     /// - It is your reponsibility to make sure that the value type makes sense
     /// - You must honor the pixel format and stride specified by the mode info
-    /// - There is no bound checking on memory accesses in release mode
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value does not fit in the frame buffer.
     #[inline]
     pub unsafe fn read_value<T>(&self, index: usize) -> T {
-        debug_assert!(
+        assert!(
             index.saturating_add(mem::size_of::<T>()) <= self.size,
             "Frame buffer accessed out of bounds"
         );
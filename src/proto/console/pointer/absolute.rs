@@ -0,0 +1,108 @@
+use crate::proto::Protocol;
+use crate::{unsafe_guid, Event, Result, Status};
+use bitflags::bitflags;
+use core::mem::MaybeUninit;
+
+/// Provides information about an absolute pointer device, such as a
+/// touchscreen or a tablet.
+#[repr(C)]
+#[unsafe_guid("8d59d32b-c655-4ae9-9b15-f25904992a43")]
+#[derive(Protocol)]
+pub struct AbsolutePointer<'boot> {
+    reset: extern "efiapi" fn(this: &mut AbsolutePointer, ext_verif: bool) -> Status,
+    get_state:
+        extern "efiapi" fn(this: &AbsolutePointer, state: *mut AbsolutePointerState) -> Status,
+    wait_for_input: Event,
+    mode: &'boot AbsolutePointerMode,
+}
+
+impl<'boot> AbsolutePointer<'boot> {
+    /// Resets the pointer device hardware.
+    ///
+    /// The `extended_verification` parameter is used to request that UEFI
+    /// performs an extended check and reset of the input device.
+    ///
+    /// # Errors
+    ///
+    /// - `DeviceError` if the device is malfunctioning and cannot be reset.
+    pub fn reset(&mut self, extended_verification: bool) -> Result {
+        (self.reset)(self, extended_verification).into()
+    }
+
+    /// Retrieves the pointer device's current state, if a state change occurred
+    /// since the last time this function was called.
+    ///
+    /// This function does not block. Use `wait_for_input_event()` with the
+    /// `BootServices::wait_for_event()` interface in order to wait for input
+    /// from the pointer device.
+    ///
+    /// # Errors
+    /// - `DeviceError` if there was an issue with the pointer device.
+    pub fn get_state(&mut self) -> Result<Option<AbsolutePointerState>> {
+        let mut state = MaybeUninit::<AbsolutePointerState>::uninit();
+
+        match (self.get_state)(self, state.as_mut_ptr()) {
+            Status::NOT_READY => Ok(None.into()),
+            other => other.into_with_val(|| unsafe { Some(state.assume_init()) }),
+        }
+    }
+
+    /// Event to be used with `BootServices::wait_for_event()` in order to wait
+    /// for input from the pointer device
+    pub fn wait_for_input_event(&self) -> &Event {
+        &self.wait_for_input
+    }
+
+    /// Returns a reference to the pointer device information.
+    pub fn mode(&self) -> &AbsolutePointerMode {
+        self.mode
+    }
+}
+
+/// Information about this absolute pointer device.
+#[derive(Debug, Copy, Clone)]
+#[repr(C)]
+pub struct AbsolutePointerMode {
+    /// The minimum value reported on the X/Y/Z axis.
+    pub absolute_min: (u64, u64, u64),
+    /// The maximum value reported on the X/Y/Z axis.
+    ///
+    /// If a value is 0, then the device does _not_ support that axis.
+    pub absolute_max: (u64, u64, u64),
+    /// Optional capabilities of the device.
+    pub attributes: AbsolutePointerAttributes,
+}
+
+bitflags! {
+    /// Optional capabilities of an absolute pointer device.
+    pub struct AbsolutePointerAttributes: u32 {
+        /// The device supports an alternate button input.
+        const SUPPORTS_ALT_ACTIVE = 0x1;
+        /// The device reports the pressure as the Z axis.
+        const SUPPORTS_PRESSURE_AS_Z = 0x2;
+    }
+}
+
+/// The current state of an absolute pointer device.
+#[derive(Debug, Copy, Clone)]
+#[repr(C)]
+pub struct AbsolutePointerState {
+    /// The position on the X/Y/Z axis, within the bounds given by
+    /// `AbsolutePointerMode`.
+    ///
+    /// If `AbsolutePointerMode` indicates an axis is not supported, it must be
+    /// ignored.
+    pub current: (u64, u64, u64),
+    /// The buttons which are currently active.
+    pub active_buttons: AbsolutePointerButtons,
+}
+
+bitflags! {
+    /// Active buttons of an absolute pointer device.
+    pub struct AbsolutePointerButtons: u32 {
+        /// The touch sensor is active.
+        const TOUCH_ACTIVE = 0x1;
+        /// The alternate button is active.
+        const ALT_ACTIVE = 0x2;
+    }
+}
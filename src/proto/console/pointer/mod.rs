@@ -4,6 +4,12 @@ use crate::proto::Protocol;
 use crate::{unsafe_guid, Event, Result, Status};
 use core::mem::MaybeUninit;
 
+mod absolute;
+pub use self::absolute::{
+    AbsolutePointer, AbsolutePointerAttributes, AbsolutePointerButtons, AbsolutePointerMode,
+    AbsolutePointerState,
+};
+
 /// Provides information about a pointer device.
 #[repr(C)]
 #[unsafe_guid("31878c87-0b75-11d5-9a4f-0090273fc14d")]
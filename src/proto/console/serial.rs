@@ -1,8 +1,10 @@
 //! Abstraction over byte stream devices, also known as serial I/O devices.
 
 use crate::proto::Protocol;
-use crate::{unsafe_guid, Result, Status};
+use crate::result::Error;
+use crate::{unsafe_guid, Result, ResultExt, Status};
 use bitflags::bitflags;
+use core::fmt;
 
 /// Provides access to a serial I/O device.
 ///
@@ -11,6 +13,9 @@ use bitflags::bitflags;
 ///
 /// Since UEFI drivers are implemented through polling, if you fail to regularly
 /// check for input/output, some data might be lost.
+///
+/// It implements the fmt::Write trait, so you can use it to send text with
+/// standard Rust constructs like the `write!()` and `writeln!()` macros.
 #[repr(C)]
 #[unsafe_guid("bb25cf6f-f1d4-11d2-9a0c-0090273fc1fd")]
 #[derive(Protocol)]
@@ -73,6 +78,22 @@ impl<'boot> Serial<'boot> {
     }
 
     /// Retrieve the device's current control bits.
+    ///
+    /// This includes the modem status bits, which can for example be used to
+    /// check whether the other end of the line is connected before starting a
+    /// transfer:
+    ///
+    /// ```no_run
+    /// # use uefi::proto::console::serial::{ControlBits, Serial};
+    /// # fn check(serial: &Serial) -> uefi::Result<bool> {
+    /// let bits = serial.get_control_bits()?.log();
+    /// let connected = bits.contains(ControlBits::CARRIER_DETECT | ControlBits::DATA_SET_READY);
+    /// # Ok(connected.into())
+    /// # }
+    /// ```
+    ///
+    /// Bits which are not supported by the device are never set. The mask of
+    /// supported bits is available in [`IoMode::control_mask`].
     pub fn get_control_bits(&self) -> Result<ControlBits> {
         let mut bits = ControlBits::empty();
         (self.get_control_bits)(self, &mut bits).into_with_val(|| bits)
@@ -99,6 +120,50 @@ impl<'boot> Serial<'boot> {
         )
     }
 
+    /// Reads exactly `data.len()` bytes from this device.
+    ///
+    /// Unlike `read`, this function keeps retrying after a partial read, as
+    /// long as some data was received before the configured
+    /// [`IoMode::timeout`] expired. If no data at all arrives within the
+    /// timeout, an error with the `TIMEOUT` status is returned. In case of
+    /// error, the error data indicates how many bytes were read in total.
+    pub fn read_exact(&mut self, data: &mut [u8]) -> Result<(), usize> {
+        let mut total = 0;
+        while total < data.len() {
+            match self.read(&mut data[total..]) {
+                Ok(completion) => return Ok(completion),
+                Err(err) => {
+                    let (status, read) = err.split();
+                    total += read;
+                    if status != Status::TIMEOUT || read == 0 {
+                        return Err(Error::new(status, total));
+                    }
+                }
+            }
+        }
+        Ok(Status::SUCCESS.into())
+    }
+
+    /// Reads bytes from this device into `buf` until the `delimiter` byte is
+    /// found, and returns the number of bytes read, including the delimiter.
+    ///
+    /// If no byte arrives within the configured [`IoMode::timeout`], an
+    /// error with the `TIMEOUT` status is returned. If `buf` fills up before
+    /// the delimiter is found, an error with the `BUFFER_TOO_SMALL` status
+    /// is returned. In both cases, the error data indicates how many bytes
+    /// were read into `buf`.
+    pub fn read_until(&mut self, delimiter: u8, buf: &mut [u8]) -> Result<usize, usize> {
+        for i in 0..buf.len() {
+            self.read(&mut buf[i..=i])
+                .warning_as_error()
+                .map_err(|err| Error::new(err.status(), i))?;
+            if buf[i] == delimiter {
+                return Ok((i + 1).into());
+            }
+        }
+        Err(Error::new(Status::BUFFER_TOO_SMALL, buf.len()))
+    }
+
     /// Writes data to this device.
     ///
     /// This operation will block until the data has been fully written or an
@@ -113,6 +178,14 @@ impl<'boot> Serial<'boot> {
     }
 }
 
+impl<'boot> fmt::Write for Serial<'boot> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.write(s.as_bytes())
+            .warning_as_error()
+            .map_err(|_| fmt::Error)
+    }
+}
+
 /// Structure representing the device's current parameters.
 ///
 /// The default values for all UART-like devices is:
@@ -134,6 +134,11 @@ impl<'boot> Output<'boot> {
     ///
     /// Note that for the foreground color you can choose any color.
     /// The background must be one of the first 8 colors.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `background` is not one of the first 8 colors, from
+    /// `Color::Black` to `Color::LightGray`.
     pub fn set_color(&mut self, foreground: Color, background: Color) -> Result {
         let fgc = foreground as usize;
         let bgc = background as usize;
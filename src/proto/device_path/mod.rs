@@ -19,6 +19,8 @@
 use crate::{proto::Protocol, unsafe_guid};
 use core::slice;
 
+pub mod text;
+
 /// Header that appears at the start of every [`DevicePath`] node.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 #[repr(C, packed)]
@@ -57,6 +59,20 @@ impl DevicePath {
         self.header.length
     }
 
+    /// Raw data of this node, following the header.
+    ///
+    /// The layout of the data depends on the `device_type` and `sub_type`.
+    /// For example, a `MEDIA_FILE_PATH` node contains a null-terminated UCS-2
+    /// path.
+    pub fn data(&self) -> &[u8] {
+        let header_size = core::mem::size_of::<DevicePathHeader>();
+        let data_size = usize::from(self.length()).saturating_sub(header_size);
+        unsafe {
+            let data_ptr = (self as *const DevicePath as *const u8).add(header_size);
+            slice::from_raw_parts(data_ptr, data_size)
+        }
+    }
+
     /// True if this node ends the entire path.
     pub fn is_end_entire(&self) -> bool {
         self.device_type() == DeviceType::END && self.sub_type() == DeviceSubType::END_ENTIRE
@@ -290,3 +306,56 @@ pub struct AcpiDevicePath {
     /// thus strings must not be used for the _UID in the ACPI name space.
     pub uid: u32,
 }
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+    use super::*;
+    use alloc::vec::Vec;
+
+    /// Build a device path from a list of (type, sub_type, data) nodes,
+    /// terminated by an end-entire node.
+    fn build_device_path(nodes: &[(DeviceType, DeviceSubType, &[u8])]) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        let end = (DeviceType::END, DeviceSubType::END_ENTIRE, &[][..]);
+        for (device_type, sub_type, data) in nodes.iter().chain([&end]) {
+            bytes.push(device_type.0);
+            bytes.push(sub_type.0);
+            bytes.extend_from_slice(&(4 + data.len() as u16).to_le_bytes());
+            bytes.extend_from_slice(data);
+        }
+        bytes
+    }
+
+    #[test]
+    fn test_device_path_nodes() {
+        // "\efi" as a null-terminated UCS-2 string.
+        let file_path: Vec<u8> = "\\efi\0"
+            .encode_utf16()
+            .flat_map(|c| c.to_le_bytes())
+            .collect();
+        let bytes = build_device_path(&[
+            (DeviceType::HARDWARE, DeviceSubType::HARDWARE_PCI, &[1, 2]),
+            (
+                DeviceType::MEDIA,
+                DeviceSubType::MEDIA_FILE_PATH,
+                &file_path,
+            ),
+        ]);
+        let path = unsafe { &*(bytes.as_ptr() as *const DevicePath) };
+
+        let nodes: Vec<_> = path.iter().collect();
+        assert_eq!(nodes.len(), 2);
+
+        assert_eq!(nodes[0].device_type(), DeviceType::HARDWARE);
+        assert_eq!(nodes[0].sub_type(), DeviceSubType::HARDWARE_PCI);
+        assert_eq!(nodes[0].length(), 6);
+        assert_eq!(nodes[0].data(), [1, 2]);
+
+        assert_eq!(nodes[1].device_type(), DeviceType::MEDIA);
+        assert_eq!(nodes[1].sub_type(), DeviceSubType::MEDIA_FILE_PATH);
+        assert_eq!(nodes[1].length(), 4 + file_path.len() as u16);
+        assert_eq!(nodes[1].data(), file_path);
+        assert!(!nodes[1].is_end_entire());
+    }
+}
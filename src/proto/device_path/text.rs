@@ -0,0 +1,113 @@
+//! `DevicePathToText` protocol
+//!
+//! This protocol converts device paths and device nodes to their textual
+//! representation, as used by the UEFI shell and firmware setup menus.
+
+use crate::proto::device_path::DevicePath;
+use crate::proto::Protocol;
+use crate::table::boot::BootServices;
+use crate::{unsafe_guid, CStr16, Char16, Result, Status};
+use core::ops::Deref;
+
+/// Device path to text protocol.
+///
+/// This protocol provides common utility functions for converting device
+/// nodes and device paths to a text representation.
+#[repr(C)]
+#[unsafe_guid("8b843e20-8132-4852-90cc-551a4e4a7f1c")]
+#[derive(Protocol)]
+pub struct DevicePathToText {
+    convert_device_node_to_text: unsafe extern "efiapi" fn(
+        device_node: *const DevicePath,
+        display_only: bool,
+        allow_shortcuts: bool,
+    ) -> *const Char16,
+    convert_device_path_to_text: unsafe extern "efiapi" fn(
+        device_path: *const DevicePath,
+        display_only: bool,
+        allow_shortcuts: bool,
+    ) -> *const Char16,
+}
+
+impl DevicePathToText {
+    /// Convert a single device node to its text representation.
+    ///
+    /// If `display_only` is true, the shorter text representation of the
+    /// node is used. If `allow_shortcuts` is true, shortcut forms of the text
+    /// representation may be used.
+    ///
+    /// Returns an `OUT_OF_RESOURCES` error if there was insufficient memory
+    /// to convert the device node.
+    pub fn convert_device_node_to_text<'boot>(
+        &self,
+        boot_services: &'boot BootServices,
+        device_node: &DevicePath,
+        display_only: bool,
+        allow_shortcuts: bool,
+    ) -> Result<PoolString<'boot>> {
+        let text = unsafe {
+            (self.convert_device_node_to_text)(device_node, display_only, allow_shortcuts)
+        };
+        PoolString::new(boot_services, text)
+    }
+
+    /// Convert a device path to its text representation.
+    ///
+    /// If `display_only` is true, the shorter text representation of the
+    /// nodes is used. If `allow_shortcuts` is true, shortcut forms of the text
+    /// representation may be used.
+    ///
+    /// Returns an `OUT_OF_RESOURCES` error if there was insufficient memory
+    /// to convert the device path.
+    pub fn convert_device_path_to_text<'boot>(
+        &self,
+        boot_services: &'boot BootServices,
+        device_path: &DevicePath,
+        display_only: bool,
+        allow_shortcuts: bool,
+    ) -> Result<PoolString<'boot>> {
+        let text = unsafe {
+            (self.convert_device_path_to_text)(device_path, display_only, allow_shortcuts)
+        };
+        PoolString::new(boot_services, text)
+    }
+}
+
+/// A null-terminated UCS-2 string allocated from the UEFI pool.
+///
+/// The string is freed when this value is dropped.
+pub struct PoolString<'boot> {
+    // The string has to be free'd with `free_pool`, so keep a reference to
+    // boot services for that purpose.
+    boot_services: &'boot BootServices,
+    text: *const Char16,
+}
+
+impl<'boot> PoolString<'boot> {
+    fn new(boot_services: &'boot BootServices, text: *const Char16) -> Result<Self> {
+        if text.is_null() {
+            Err(Status::OUT_OF_RESOURCES.into())
+        } else {
+            Ok(Self {
+                boot_services,
+                text,
+            }
+            .into())
+        }
+    }
+}
+
+impl<'boot> Deref for PoolString<'boot> {
+    type Target = CStr16;
+
+    fn deref(&self) -> &Self::Target {
+        unsafe { CStr16::from_ptr(self.text) }
+    }
+}
+
+impl<'boot> Drop for PoolString<'boot> {
+    fn drop(&mut self) {
+        // Ignore the result, we can't do anything about an error here.
+        let _ = self.boot_services.free_pool(self.text as *mut u8);
+    }
+}
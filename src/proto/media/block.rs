@@ -59,12 +59,15 @@ impl BlockIO {
     ///     operation.
     /// * `uefi::Status::NO_MEDIA`           There is no media in the device.
     /// * `uefi::Status::MEDIA_CHANGED`      The `media_id` is not for the current media.
-    /// * `uefi::Status::BAD_BUFFER_SIZE`    The buffer size parameter is not a multiple of the intrinsic block size of
-    ///     the device.
+    /// * `uefi::Status::BAD_BUFFER_SIZE`    The buffer size is not a multiple of the intrinsic block size of the
+    ///     device. This is checked before calling the firmware.
     /// * `uefi::Status::INVALID_PARAMETER`  The read request contains LBAs that are not valid, or the buffer is not on
     ///     proper alignment.
     pub fn read_blocks(&self, media_id: u32, lba: Lba, buffer: &mut [u8]) -> Result {
         let buffer_size = buffer.len();
+        if !self.is_valid_buffer_size(buffer_size) {
+            return Status::BAD_BUFFER_SIZE.into();
+        }
         (self.read_blocks)(self, media_id, lba, buffer_size, buffer.as_mut_ptr()).into()
     }
 
@@ -81,12 +84,15 @@ impl BlockIO {
     /// * `uefi::Status::MEDIA_CHANGED`         The `media_id` is not for the current media.
     /// * `uefi::Status::DEVICE_ERROR`          The device reported an error while attempting to perform the write
     ///     operation.
-    /// * `uefi::Status::BAD_BUFFER_SIZE`       The buffer size parameter is not a multiple of the intrinsic block size
-    ///     of the device.
+    /// * `uefi::Status::BAD_BUFFER_SIZE`       The buffer size is not a multiple of the intrinsic block size of the
+    ///     device. This is checked before calling the firmware.
     /// * `uefi::Status::INVALID_PARAMETER`     The write request contains LBAs that are not valid, or the buffer is not
     ///     on proper alignment.
     pub fn write_blocks(&mut self, media_id: u32, lba: Lba, buffer: &[u8]) -> Result {
         let buffer_size = buffer.len();
+        if !self.is_valid_buffer_size(buffer_size) {
+            return Status::BAD_BUFFER_SIZE.into();
+        }
         (self.write_blocks)(self, media_id, lba, buffer_size, buffer.as_ptr()).into()
     }
 
@@ -98,6 +104,12 @@ impl BlockIO {
     pub fn flush_blocks(&mut self) -> Result {
         (self.flush_blocks)(self).into()
     }
+
+    /// Whether `buffer_size` is a multiple of the media's block size.
+    fn is_valid_buffer_size(&self, buffer_size: usize) -> bool {
+        let block_size = self.media().block_size() as usize;
+        buffer_size.checked_rem(block_size) == Some(0)
+    }
 }
 
 /// EFI LBA type
@@ -0,0 +1,77 @@
+//! Disk I/O protocols.
+
+use crate::proto::Protocol;
+use crate::{unsafe_guid, Result, Status};
+
+/// The Disk I/O protocol.
+///
+/// This protocol is used to abstract the block accesses of the Block I/O
+/// protocol to a more general offset-length protocol. Firmware is
+/// responsible for adding this protocol to any Block I/O interface that
+/// appears in the system that does not already have a Disk I/O protocol,
+/// so it is found on handles which also support `BlockIO`.
+#[repr(C)]
+#[unsafe_guid("ce345171-ba0b-11d2-8e4f-00a0c969723b")]
+#[derive(Protocol)]
+pub struct DiskIO {
+    revision: u64,
+    read_disk: extern "efiapi" fn(
+        this: &DiskIO,
+        media_id: u32,
+        offset: u64,
+        len: usize,
+        buffer: *mut u8,
+    ) -> Status,
+    write_disk: extern "efiapi" fn(
+        this: &mut DiskIO,
+        media_id: u32,
+        offset: u64,
+        len: usize,
+        buffer: *const u8,
+    ) -> Status,
+}
+
+impl DiskIO {
+    /// Reads bytes from the disk device.
+    ///
+    /// Unlike `BlockIO::read_blocks`, neither the offset nor the buffer
+    /// length need to be aligned on block boundaries.
+    ///
+    /// # Arguments
+    /// * `media_id`    ID of the medium to be read, see `BlockIOMedia::media_id`.
+    /// * `offset`      Starting byte offset on the logical block I/O device to read from.
+    /// * `buffer`      Pointer to a buffer to read into.
+    ///
+    /// # Errors
+    /// * `uefi::Status::INVALID_PARAMETER` The read request contains device addresses that
+    ///   are not valid for the device.
+    /// * `uefi::Status::DEVICE_ERROR`      The device reported an error while performing
+    ///   the read operation.
+    /// * `uefi::Status::NO_MEDIA`          There is no medium in the device.
+    /// * `uefi::Status::MEDIA_CHANGED`     `media_id` is not for the current medium.
+    pub fn read_disk(&self, media_id: u32, offset: u64, buffer: &mut [u8]) -> Result {
+        (self.read_disk)(self, media_id, offset, buffer.len(), buffer.as_mut_ptr()).into()
+    }
+
+    /// Writes bytes to the disk device.
+    ///
+    /// Unlike `BlockIO::write_blocks`, neither the offset nor the buffer
+    /// length need to be aligned on block boundaries.
+    ///
+    /// # Arguments
+    /// * `media_id`    ID of the medium to be written to, see `BlockIOMedia::media_id`.
+    /// * `offset`      Starting byte offset on the logical block I/O device to write to.
+    /// * `buffer`      Pointer to a buffer to write from.
+    ///
+    /// # Errors
+    /// * `uefi::Status::INVALID_PARAMETER` The write request contains device addresses that
+    ///   are not valid for the device.
+    /// * `uefi::Status::DEVICE_ERROR`      The device reported an error while performing
+    ///   the write operation.
+    /// * `uefi::Status::NO_MEDIA`          There is no medium in the device.
+    /// * `uefi::Status::MEDIA_CHANGED`     `media_id` is not for the current medium.
+    /// * `uefi::Status::WRITE_PROTECTED`   The device cannot be written to.
+    pub fn write_disk(&mut self, media_id: u32, offset: u64, buffer: &[u8]) -> Result {
+        (self.write_disk)(self, media_id, offset, buffer.len(), buffer.as_ptr()).into()
+    }
+}
@@ -1,7 +1,13 @@
 use super::{File, FileHandle, FileInfo, FromUefi, RegularFile};
+#[cfg(feature = "exts")]
+use super::{FileAttribute, FileMode};
 use crate::data_types::Align;
 use crate::prelude::*;
 use crate::Result;
+#[cfg(feature = "exts")]
+use crate::{CStr16, Status};
+#[cfg(feature = "exts")]
+use alloc_api::{vec, vec::Vec};
 use core::ffi::c_void;
 
 /// A `FileHandle` that is also a directory.
@@ -61,6 +67,118 @@ impl Directory {
     pub fn reset_entry_readout(&mut self) -> Result {
         self.0.set_position(0)
     }
+
+    /// Open a file by walking a `\`-separated `path`, relative to this
+    /// directory.
+    ///
+    /// Each intermediate directory is opened read-only and closed once the
+    /// next component has been opened. The last component is opened with
+    /// `open_mode` and `attributes`, as in `File::open`. A leading `\` and
+    /// empty components are ignored, so `\EFI\BOOT` and `EFI\BOOT` both
+    /// refer to the `BOOT` directory inside of this directory's `EFI`
+    /// subdirectory.
+    ///
+    /// # Errors
+    /// * `uefi::Status::INVALID_PARAMETER`  The path has no components
+    ///
+    /// See `File::open` for the errors returned when opening a component.
+    #[cfg(feature = "exts")]
+    pub fn open_path(
+        &mut self,
+        path: &CStr16,
+        open_mode: FileMode,
+        attributes: FileAttribute,
+    ) -> Result<FileHandle> {
+        let mut components = path
+            .to_u16_slice()
+            .split(|&c| c == u16::from(b'\\'))
+            .filter(|component| !component.is_empty())
+            .peekable();
+
+        let mut name = Vec::new();
+        let mut parent: Option<FileHandle> = None;
+        while let Some(component) = components.next() {
+            name.clear();
+            name.extend_from_slice(component);
+            name.push(0);
+            // `path` is a valid string, so its components are too.
+            let name = CStr16::from_u16_with_nul(&name).unwrap();
+
+            let is_last = components.peek().is_none();
+            let (mode, attrs) = if is_last {
+                (open_mode, attributes)
+            } else {
+                (FileMode::Read, FileAttribute::empty())
+            };
+
+            let file = match &mut parent {
+                Some(parent) => parent.open(name, mode, attrs)?,
+                None => self.open(name, mode, attrs)?,
+            };
+            if is_last {
+                return Ok(file);
+            }
+            // This closes the previous parent directory.
+            parent = Some(file.log());
+        }
+
+        Err(Status::INVALID_PARAMETER.into())
+    }
+
+    /// Enumerate the directory entries, starting over from the first one.
+    ///
+    /// Unlike `read_entry`, the returned `DirEntryIter` manages the storage
+    /// of the entries itself, growing it as needed.
+    #[cfg(feature = "exts")]
+    pub fn entries(&mut self) -> Result<DirEntryIter<'_>> {
+        let completion = self.reset_entry_readout()?;
+        Ok(completion.map(|()| DirEntryIter {
+            directory: self,
+            buffer: vec![0; 128 + FileInfo::alignment()],
+        }))
+    }
+}
+
+/// Reads the entries of a `Directory`, see `Directory::entries`.
+///
+/// Each entry is stored in a buffer owned by the iterator, so this can't
+/// implement `Iterator`: every entry has to be dropped before reading the
+/// next one.
+#[cfg(feature = "exts")]
+pub struct DirEntryIter<'dir> {
+    directory: &'dir mut Directory,
+    buffer: Vec<u8>,
+}
+
+#[cfg(feature = "exts")]
+impl<'dir> DirEntryIter<'dir> {
+    /// Read the next directory entry, or `None` once all entries were read.
+    ///
+    /// # Errors
+    /// See `Directory::read_entry`, except that `BUFFER_TOO_SMALL` is handled
+    /// by growing the buffer and retrying.
+    pub fn next_entry(&mut self) -> Result<Option<&mut FileInfo>> {
+        // Returning the entry from within the loop would keep the buffer
+        // borrowed across iterations, so only check for its presence here.
+        let completion = loop {
+            let buffer = FileInfo::align_buf(&mut self.buffer).unwrap();
+            match self.directory.read_entry(buffer) {
+                Ok(completion) => break completion.map(|entry| entry.is_some()),
+                Err(err) => match err.split() {
+                    (Status::BUFFER_TOO_SMALL, Some(size)) => {
+                        self.buffer.resize(size + FileInfo::alignment(), 0);
+                    }
+                    (status, _) => return Err(status.into()),
+                },
+            }
+        };
+
+        let buffer = FileInfo::align_buf(&mut self.buffer).unwrap();
+        Ok(completion.map(|found| {
+            // The entry was read into the same aligned storage just above.
+            found.then(|| unsafe { FileInfo::from_uefi(buffer.as_mut_ptr() as *mut c_void) })
+        }))
+    }
 }
 
 impl File for Directory {
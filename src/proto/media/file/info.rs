@@ -100,6 +100,24 @@ impl<Header> NamedFileProtocolInfo<Header> {
     }
 }
 
+impl<Header: Clone> NamedFileProtocolInfo<Header> {
+    /// Copy this structure into user-provided storage
+    ///
+    /// The header and name are duplicated into the provided storage buffer,
+    /// which lets you modify a copy of information obtained with
+    /// `File::get_info()` before passing it to `File::set_info()`.
+    ///
+    /// The same storage size and alignment requirements as for the
+    /// constructors of this struct apply.
+    pub fn clone_into_buf<'buf>(
+        &self,
+        storage: &'buf mut [u8],
+    ) -> core::result::Result<&'buf mut Self, FileInfoCreationError> {
+        let name = unsafe { CStr16::from_ptr(&self.name[0]) };
+        Self::new_impl(storage, self.header.clone(), name)
+    }
+}
+
 impl<Header> Align for NamedFileProtocolInfo<Header> {
     fn alignment() -> usize {
         cmp::max(mem::align_of::<Header>(), mem::align_of::<Char16>())
@@ -149,7 +167,7 @@ pub enum FileInfoCreationError {
 pub type FileInfo = NamedFileProtocolInfo<FileInfoHeader>;
 
 /// Header for generic file information
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 #[repr(C)]
 pub struct FileInfoHeader {
     size: u64,
@@ -230,10 +248,133 @@ impl FileInfo {
     pub fn file_name(&self) -> &CStr16 {
         unsafe { CStr16::from_ptr(&self.name[0]) }
     }
+
+    /// Set the file size
+    ///
+    /// This is ignored by `set_info()` on directories.
+    pub fn set_file_size(&mut self, file_size: u64) {
+        self.header.file_size = file_size;
+    }
+
+    /// Set the time when the file was created
+    ///
+    /// A zero time is ignored by `set_info()`.
+    pub fn set_create_time(&mut self, create_time: Time) {
+        self.header.create_time = create_time;
+    }
+
+    /// Set the time when the file was last accessed
+    ///
+    /// A zero time is ignored by `set_info()`.
+    pub fn set_last_access_time(&mut self, last_access_time: Time) {
+        self.header.last_access_time = last_access_time;
+    }
+
+    /// Set the time when the file's contents were last modified
+    ///
+    /// A zero time is ignored by `set_info()`.
+    pub fn set_modification_time(&mut self, modification_time: Time) {
+        self.header.modification_time = modification_time;
+    }
+
+    /// Set the attribute bits for the file
+    ///
+    /// The `FileAttribute::DIRECTORY` bit must match the file's actual type.
+    pub fn set_attribute(&mut self, attribute: FileAttribute) {
+        self.header.attribute = attribute;
+    }
 }
 
 impl FileProtocolInfo for FileInfo {}
 
+/// Builder for a `FileInfo` structure
+///
+/// This is a more readable alternative to `FileInfo::new` for the common
+/// case of `set_info()` calls that only change some of the fields. All times
+/// default to zero, which `set_info()` treats as "do not change", and the
+/// sizes and attributes default to zero.
+///
+/// The structure will be created in-place within the provided storage buffer
+/// when calling `build`. The same size and alignment requirements as for
+/// `FileInfo::new` apply.
+#[derive(Debug)]
+pub struct FileInfoBuilder<'buf, 'name> {
+    storage: &'buf mut [u8],
+    file_name: &'name CStr16,
+    file_size: u64,
+    physical_size: u64,
+    create_time: Time,
+    last_access_time: Time,
+    modification_time: Time,
+    attribute: FileAttribute,
+}
+
+impl<'buf, 'name> FileInfoBuilder<'buf, 'name> {
+    /// Start building a `FileInfo` with the given storage and file name
+    pub fn new(storage: &'buf mut [u8], file_name: &'name CStr16) -> Self {
+        Self {
+            storage,
+            file_name,
+            file_size: 0,
+            physical_size: 0,
+            create_time: Time::invalid(),
+            last_access_time: Time::invalid(),
+            modification_time: Time::invalid(),
+            attribute: FileAttribute::empty(),
+        }
+    }
+
+    /// Set the file size
+    pub fn file_size(mut self, file_size: u64) -> Self {
+        self.file_size = file_size;
+        self
+    }
+
+    /// Set the physical space consumed by the file
+    pub fn physical_size(mut self, physical_size: u64) -> Self {
+        self.physical_size = physical_size;
+        self
+    }
+
+    /// Set the time when the file was created
+    pub fn create_time(mut self, create_time: Time) -> Self {
+        self.create_time = create_time;
+        self
+    }
+
+    /// Set the time when the file was last accessed
+    pub fn last_access_time(mut self, last_access_time: Time) -> Self {
+        self.last_access_time = last_access_time;
+        self
+    }
+
+    /// Set the time when the file's contents were last modified
+    pub fn modification_time(mut self, modification_time: Time) -> Self {
+        self.modification_time = modification_time;
+        self
+    }
+
+    /// Set the attribute bits for the file
+    pub fn attribute(mut self, attribute: FileAttribute) -> Self {
+        self.attribute = attribute;
+        self
+    }
+
+    /// Create the `FileInfo` structure in the storage buffer
+    pub fn build(self) -> core::result::Result<&'buf mut FileInfo, FileInfoCreationError> {
+        FileInfo::new(
+            self.storage,
+            self.file_size,
+            self.physical_size,
+            self.create_time,
+            self.last_access_time,
+            self.modification_time,
+            self.attribute,
+            self.file_name,
+        )
+    }
+}
+
 /// System volume information
 ///
 /// May only be obtained on the root directory's file handle.
@@ -244,7 +385,7 @@ impl FileProtocolInfo for FileInfo {}
 pub type FileSystemInfo = NamedFileProtocolInfo<FileSystemInfoHeader>;
 
 /// Header for system volume information
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 #[repr(C)]
 pub struct FileSystemInfoHeader {
     size: u64,
@@ -320,7 +461,7 @@ impl FileProtocolInfo for FileSystemInfo {}
 pub type FileSystemVolumeLabel = NamedFileProtocolInfo<FileSystemVolumeLabelHeader>;
 
 /// Header for system volume label information
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 #[repr(C)]
 pub struct FileSystemVolumeLabelHeader {}
 
@@ -395,6 +536,103 @@ mod tests {
         assert_eq!(info.file_name(), name);
     }
 
+    #[test]
+    fn test_file_info_setters() {
+        let mut storage = vec![0; 128];
+
+        let time = Time::new(1970, 1, 1, 0, 0, 0, 0, 0, Daylight::IN_DAYLIGHT);
+        let name = CString16::try_from("test_name").unwrap();
+        let info = FileInfo::new(
+            &mut storage,
+            123,
+            456,
+            time,
+            time,
+            time,
+            FileAttribute::READ_ONLY,
+            &name,
+        )
+        .unwrap();
+
+        // Flip the read-only bit.
+        info.set_attribute(info.attribute() ^ FileAttribute::READ_ONLY);
+        assert_eq!(info.attribute(), FileAttribute::empty());
+
+        let new_time = Time::new(1980, 2, 3, 4, 5, 6, 0, 0, Daylight::empty());
+        info.set_file_size(789);
+        info.set_create_time(new_time);
+        info.set_last_access_time(new_time);
+        info.set_modification_time(new_time);
+        assert_eq!(info.file_size(), 789);
+        assert_eq!(info.create_time(), &new_time);
+        assert_eq!(info.last_access_time(), &new_time);
+        assert_eq!(info.modification_time(), &new_time);
+
+        // The size and name are unaffected.
+        assert_eq!(info.header.size, 104);
+        assert_eq!(info.file_name(), name);
+    }
+
+    #[test]
+    fn test_file_info_builder() {
+        let mut storage = vec![0; 128];
+
+        let modification_time = Time::new(1972, 1, 1, 0, 0, 0, 0, 0, Daylight::IN_DAYLIGHT);
+        let name = CString16::try_from("test_name").unwrap();
+        let info = FileInfoBuilder::new(&mut storage, &name)
+            .file_size(123)
+            .attribute(FileAttribute::READ_ONLY)
+            .modification_time(modification_time)
+            .build()
+            .unwrap();
+
+        assert_eq!(info.header.size, 104);
+        assert_eq!(info.file_size(), 123);
+        assert_eq!(info.physical_size(), 0);
+        assert_eq!(info.create_time(), &Time::invalid());
+        assert_eq!(info.last_access_time(), &Time::invalid());
+        assert_eq!(info.modification_time(), &modification_time);
+        assert_eq!(info.attribute(), FileAttribute::READ_ONLY);
+        assert_eq!(info.file_name(), name);
+
+        // The storage size requirement is the same as for `FileInfo::new`.
+        let mut storage = vec![0; 64];
+        assert_eq!(
+            FileInfoBuilder::new(&mut storage, &name)
+                .build()
+                .unwrap_err(),
+            FileInfoCreationError::InsufficientStorage(100)
+        );
+    }
+
+    #[test]
+    fn test_clone_into_buf() {
+        let mut storage = vec![0; 128];
+
+        let name = CString16::try_from("test_name").unwrap();
+        let info = FileInfoBuilder::new(&mut storage, &name)
+            .file_size(123)
+            .attribute(FileAttribute::READ_ONLY)
+            .build()
+            .unwrap();
+
+        let mut copy_storage = vec![0; 128];
+        let copy = info.clone_into_buf(&mut copy_storage).unwrap();
+        copy.set_file_size(456);
+
+        assert_eq!(copy.header.size, 104);
+        assert_eq!(copy.file_size(), 456);
+        assert_eq!(copy.attribute(), FileAttribute::READ_ONLY);
+        assert_eq!(copy.file_name(), name);
+        assert_eq!(info.file_size(), 123);
+
+        let mut small_storage = vec![0; 64];
+        assert_eq!(
+            info.clone_into_buf(&mut small_storage).unwrap_err(),
+            FileInfoCreationError::InsufficientStorage(100)
+        );
+    }
+
     #[test]
     fn test_file_system_info() {
         let mut storage = vec![0; 128];
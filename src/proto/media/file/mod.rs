@@ -19,9 +19,12 @@ use core::ffi::c_void;
 use core::mem;
 use core::ptr;
 
+#[cfg(feature = "exts")]
+pub use self::dir::DirEntryIter;
 pub use self::info::{
-    FileInfo, FileInfoHeader, FileProtocolInfo, FileSystemInfo, FileSystemInfoHeader,
-    FileSystemVolumeLabel, FileSystemVolumeLabelHeader, FromUefi, NamedFileProtocolInfo,
+    FileInfo, FileInfoBuilder, FileInfoCreationError, FileInfoHeader, FileProtocolInfo,
+    FileSystemInfo, FileSystemInfoHeader, FileSystemVolumeLabel, FileSystemVolumeLabelHeader,
+    FromUefi, NamedFileProtocolInfo,
 };
 pub use self::{dir::Directory, regular::RegularFile};
 
@@ -166,6 +169,9 @@ pub trait File: Sized {
 
     #[cfg(feature = "exts")]
     /// Get the dynamically allocated info for a file
+    ///
+    /// Unlike `get_info`, this queries the required size and allocates
+    /// correctly aligned storage for the information itself.
     fn get_boxed_info<Info: FileProtocolInfo + ?Sized>(&mut self) -> Result<Box<Info>> {
         // Initially try get_info with an empty array, this should always fail
         // as all Info types at least need room for a null-terminator.
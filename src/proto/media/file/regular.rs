@@ -1,5 +1,11 @@
+#[cfg(feature = "exts")]
+use super::FileInfo;
 use super::{File, FileHandle, FileInternal};
+#[cfg(feature = "exts")]
+use crate::prelude::*;
 use crate::{Result, Status};
+#[cfg(feature = "exts")]
+use alloc_api::{vec, vec::Vec};
 
 /// A `FileHandle` that is also a regular (data) file.
 ///
@@ -50,6 +56,38 @@ impl RegularFile {
         )
     }
 
+    /// Read the rest of the file, from the current position to its end.
+    ///
+    /// The buffer is sized using the file size from `FileInfo`, and is
+    /// grown as needed if the file turns out to be larger than that.
+    ///
+    /// # Errors
+    /// See `File::get_info` and `read`.
+    #[cfg(feature = "exts")]
+    pub fn read_to_end(&mut self) -> Result<Vec<u8>> {
+        let file_size = self.get_boxed_info::<FileInfo>()?.log().file_size();
+        let position = self.get_position()?.log();
+        let remaining = file_size.saturating_sub(position) as usize;
+
+        let mut buffer = vec![0; remaining];
+        let mut len = 0;
+        loop {
+            if len == buffer.len() {
+                // Either the file grew, or we need to make sure that the
+                // end of the file has been reached.
+                buffer.resize(len + len.max(512), 0);
+            }
+            let read = self.read(&mut buffer[len..]).discard_errdata()?.log();
+            if read == 0 {
+                break;
+            }
+            len += read;
+        }
+        buffer.truncate(len);
+
+        Ok(buffer.into())
+    }
+
     /// Write data to file
     ///
     /// Write `buffer` to file, increment the file pointer.
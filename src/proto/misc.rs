@@ -0,0 +1,88 @@
+//! Miscellaneous protocols.
+
+use crate::proto::Protocol;
+use crate::{unsafe_guid, Result, Status};
+
+/// Protocol for retrieving a high-resolution timestamp counter.
+#[repr(C)]
+#[unsafe_guid("afbfde41-2e6e-4262-ba65-62b9236e5495")]
+#[derive(Protocol)]
+pub struct Timestamp {
+    get_timestamp: extern "efiapi" fn() -> u64,
+    get_properties: extern "efiapi" fn(properties: *mut TimestampProperties) -> Status,
+}
+
+impl Timestamp {
+    /// Get the current value of the timestamp counter.
+    pub fn get_timestamp(&self) -> u64 {
+        (self.get_timestamp)()
+    }
+
+    /// Get the properties of the timestamp counter.
+    pub fn get_properties(&self) -> Result<TimestampProperties> {
+        let mut properties = TimestampProperties::default();
+        (self.get_properties)(&mut properties).into_with_val(|| properties)
+    }
+}
+
+/// Properties of the timestamp counter.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
+#[repr(C)]
+pub struct TimestampProperties {
+    /// Timestamp counter frequency, in Hz.
+    pub frequency: u64,
+    /// The maximum value of the timestamp counter before it rolls over to
+    /// zero. For example, a 24-bit counter has an end value of `0xff_ffff`.
+    pub end_value: u64,
+}
+
+impl TimestampProperties {
+    /// Compute the number of nanoseconds elapsed between two timestamps,
+    /// taking into account a single rollover of the counter.
+    ///
+    /// Returns `None` if the frequency is zero, if a timestamp is larger than
+    /// `end_value`, or if the result does not fit in a `u64`.
+    pub fn elapsed_nanos(&self, start: u64, end: u64) -> Option<u64> {
+        if self.frequency == 0 || start > self.end_value || end > self.end_value {
+            return None;
+        }
+        let ticks = if end >= start {
+            end - start
+        } else {
+            (self.end_value - start).checked_add(end)?.checked_add(1)?
+        };
+        let nanos = u128::from(ticks) * 1_000_000_000 / u128::from(self.frequency);
+        u64::try_from(nanos).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_elapsed_nanos() {
+        let properties = TimestampProperties {
+            frequency: 1_000_000,
+            end_value: 0xff_ffff,
+        };
+        assert_eq!(properties.elapsed_nanos(10, 10), Some(0));
+        assert_eq!(properties.elapsed_nanos(10, 15), Some(5_000));
+        assert_eq!(properties.elapsed_nanos(0xff_fffe, 1), Some(3_000));
+
+        // Timestamps past the end value are invalid.
+        assert_eq!(properties.elapsed_nanos(0x100_0000, 1), None);
+        assert_eq!(properties.elapsed_nanos(1, 0x100_0000), None);
+
+        // Too many nanoseconds to fit in a `u64`.
+        let properties = TimestampProperties {
+            frequency: 1,
+            end_value: u64::MAX,
+        };
+        assert_eq!(properties.elapsed_nanos(0, u64::MAX), None);
+        assert_eq!(properties.elapsed_nanos(u64::MAX, 0), Some(1_000_000_000));
+
+        let properties = TimestampProperties::default();
+        assert_eq!(properties.elapsed_nanos(10, 15), None);
+    }
+}
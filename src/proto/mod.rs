@@ -24,14 +24,30 @@ use crate::Identify;
 /// #[derive(Protocol)]
 /// struct DummyProtocol {}
 /// ```
+///
+/// The `protocol` attribute does both in one go:
+///
+/// ```
+/// #![feature(negative_impls)]
+/// use uefi::proto::protocol;
+/// #[protocol("12345678-9abc-def0-1234-56789abcdef0")]
+/// struct DummyProtocol {}
+/// ```
 pub trait Protocol: Identify {}
 
-pub use uefi_macros::Protocol;
+pub use uefi_macros::{protocol, Protocol};
 
 pub mod console;
 pub mod debug;
 pub mod device_path;
 pub mod loaded_image;
 pub mod media;
+pub mod misc;
+pub mod network;
 pub mod pi;
+pub mod rng;
+pub mod security;
+pub mod shell;
+pub mod shell_params;
 pub mod shim;
+pub mod string;
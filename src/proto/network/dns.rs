@@ -0,0 +1,286 @@
+//! DNS protocols, which resolve host names to IPv4 addresses.
+
+use crate::proto::Protocol;
+use crate::result::Error;
+use crate::table::boot::{BootServices, EventType, Tpl};
+use crate::{unsafe_guid, CStr16, Char16, Completion, Event, Handle, Result, Status};
+use core::marker::PhantomData;
+use core::{ptr, slice};
+
+#[cfg(feature = "exts")]
+use crate::alloc_api::vec::Vec;
+
+/// Creates and destroys instances of the [`Dns4`] protocol.
+///
+/// Each instance of `Dns4` is installed on its own child handle, which can
+/// then be opened with `BootServices::open_protocol`.
+#[repr(C)]
+#[unsafe_guid("b625b186-e063-44f7-8905-6a74dc6f52b4")]
+#[derive(Protocol)]
+pub struct Dns4ServiceBinding {
+    create_child: extern "efiapi" fn(
+        this: &mut Dns4ServiceBinding,
+        child_handle: &mut Option<Handle>,
+    ) -> Status,
+    destroy_child:
+        extern "efiapi" fn(this: &mut Dns4ServiceBinding, child_handle: Handle) -> Status,
+}
+
+impl Dns4ServiceBinding {
+    /// Create a new handle with an instance of the `Dns4` protocol
+    /// installed on it.
+    pub fn create_child(&mut self) -> Result<Handle> {
+        let mut child_handle = None;
+        (self.create_child)(self, &mut child_handle)
+            .into_with_val(|| child_handle.expect("create_child returned a null handle"))
+    }
+
+    /// Destroy a handle created by `create_child`, and the `Dns4` instance
+    /// installed on it.
+    pub fn destroy_child(&mut self, child_handle: Handle) -> Result {
+        (self.destroy_child)(self, child_handle).into()
+    }
+}
+
+/// Resolves host names to IPv4 addresses.
+///
+/// Instances of this protocol are created with [`Dns4ServiceBinding`], and
+/// must be configured with `configure` before use.
+#[repr(C)]
+#[unsafe_guid("ae3d28cc-e05b-4fa1-a011-7eb55a3f1401")]
+#[derive(Protocol)]
+pub struct Dns4 {
+    _get_mode_data: usize,
+    configure: extern "efiapi" fn(this: &mut Dns4, config: *const Dns4ConfigData) -> Status,
+    host_name_to_ip: extern "efiapi" fn(
+        this: &mut Dns4,
+        host_name: *const Char16,
+        token: &mut CompletionToken,
+    ) -> Status,
+    _ip_to_host_name: usize,
+    _general_look_up: usize,
+    _update_dns_cache: usize,
+    poll: extern "efiapi" fn(this: &mut Dns4) -> Status,
+    cancel: extern "efiapi" fn(this: &mut Dns4, token: *mut CompletionToken) -> Status,
+}
+
+impl Dns4 {
+    /// Configure this instance, which must be done before any lookup.
+    ///
+    /// # Errors
+    /// * `uefi::Status::NO_MAPPING`         The default station address is not available yet.
+    /// * `uefi::Status::ALREADY_STARTED`    The instance is already configured.
+    /// * `uefi::Status::INVALID_PARAMETER`  The configuration is invalid.
+    pub fn configure(&mut self, config: &Dns4ConfigData) -> Result {
+        (self.configure)(self, config).into()
+    }
+
+    /// Reset the configuration of this instance, and cancel all pending
+    /// lookups.
+    pub fn reset_configuration(&mut self) -> Result {
+        (self.configure)(self, ptr::null()).into()
+    }
+
+    /// Resolve `host_name` to IPv4 addresses, and write them to `buffer`.
+    ///
+    /// This function blocks until the lookup completes or times out. The
+    /// part of `buffer` that was filled is returned.
+    ///
+    /// # Errors
+    /// * `uefi::Status::TIMEOUT`           The DNS servers did not respond, even after retrying.
+    /// * `uefi::Status::NOT_FOUND`         The host name could not be resolved.
+    /// * `uefi::Status::NOT_STARTED`       The instance has not been configured.
+    /// * `uefi::Status::BUFFER_TOO_SMALL`  `buffer` is too small; the number of addresses is
+    ///   returned.
+    pub fn host_name_to_ip_with_buf<'buf>(
+        &mut self,
+        boot_services: &BootServices,
+        host_name: &CStr16,
+        buffer: &'buf mut [[u8; 4]],
+    ) -> Result<&'buf [[u8; 4]], Option<usize>> {
+        let (status, response) = self
+            .host_name_to_ip_raw(boot_services, host_name)
+            .map_err(|err| Error::new(err.status(), None))?
+            .split();
+        let addresses = response.addresses();
+
+        match buffer.get_mut(..addresses.len()) {
+            Some(buffer) => {
+                buffer.copy_from_slice(addresses);
+                Ok(Completion::new(status, &*buffer))
+            }
+            None => Err(Error::new(Status::BUFFER_TOO_SMALL, Some(addresses.len()))),
+        }
+    }
+
+    /// Resolve `host_name` to IPv4 addresses.
+    ///
+    /// This function blocks until the lookup completes or times out.
+    ///
+    /// # Errors
+    /// * `uefi::Status::TIMEOUT`      The DNS servers did not respond, even after retrying.
+    /// * `uefi::Status::NOT_FOUND`    The host name could not be resolved.
+    /// * `uefi::Status::NOT_STARTED`  The instance has not been configured.
+    #[cfg(feature = "exts")]
+    pub fn host_name_to_ip(
+        &mut self,
+        boot_services: &BootServices,
+        host_name: &CStr16,
+    ) -> Result<Vec<[u8; 4]>> {
+        self.host_name_to_ip_raw(boot_services, host_name)
+            .map(|completion| completion.map(|response| response.addresses().to_vec()))
+    }
+
+    /// Start a lookup and poll the driver until it completes.
+    fn host_name_to_ip_raw<'boot>(
+        &mut self,
+        boot_services: &'boot BootServices,
+        host_name: &CStr16,
+    ) -> Result<HostToAddrResponse<'boot>> {
+        let event =
+            unsafe { boot_services.create_event(EventType::empty(), Tpl::CALLBACK, None, None) }?
+                .log();
+
+        let mut token = CompletionToken {
+            event: unsafe { event.unsafe_clone() },
+            status: Status::SUCCESS,
+            retry_count: 0,
+            retry_interval: 0,
+            rsp_data: ptr::null_mut(),
+        };
+
+        let status = (self.host_name_to_ip)(self, host_name.as_ptr(), &mut token);
+        if status.is_error() {
+            let _ = boot_services.close_event(event);
+            return Err(status.into());
+        }
+
+        // The driver needs to be polled to process incoming packets, and
+        // signals the event once the lookup is complete.
+        loop {
+            let _ = (self.poll)(self);
+            match boot_services.check_event(unsafe { event.unsafe_clone() }) {
+                Ok(signaled) if signaled.unwrap() => break,
+                Ok(_) => {}
+                Err(err) => {
+                    // The token must not be used by the driver anymore once
+                    // it goes out of scope.
+                    let _ = (self.cancel)(self, &mut token);
+                    let _ = boot_services.close_event(event);
+                    return Err(err);
+                }
+            }
+        }
+        let _ = boot_services.close_event(event);
+
+        let response = HostToAddrResponse {
+            boot_services,
+            data: token.rsp_data,
+        };
+        token.status.into_with_val(|| response)
+    }
+}
+
+/// Configuration of a [`Dns4`] instance (`EFI_DNS4_CONFIG_DATA`).
+#[derive(Debug)]
+#[repr(C)]
+pub struct Dns4ConfigData<'a> {
+    dns_server_list_count: usize,
+    dns_server_list: *const [u8; 4],
+    /// Use the default station address and subnet mask of the interface,
+    /// ignoring `station_ip` and `subnet_mask`.
+    pub use_default_setting: bool,
+    /// Cache the results of lookups.
+    pub enable_dns_cache: bool,
+    /// Transport protocol used for lookups. Only UDP (17) is supported.
+    pub protocol: u8,
+    /// Station address, if `use_default_setting` is false.
+    pub station_ip: [u8; 4],
+    /// Subnet mask, if `use_default_setting` is false.
+    pub subnet_mask: [u8; 4],
+    /// Local port used for lookups, or 0 to pick one at random.
+    pub local_port: u16,
+    /// Number of times a lookup is retried before timing out.
+    pub retry_count: u32,
+    /// Time to wait for a response before retrying, in seconds.
+    pub retry_interval: u32,
+    _marker: PhantomData<&'a [[u8; 4]]>,
+}
+
+impl<'a> Dns4ConfigData<'a> {
+    /// Create a configuration using the default station address, with UDP
+    /// lookups that are retried 3 times.
+    ///
+    /// If `dns_servers` is empty, the DNS servers are obtained through DHCP.
+    pub fn new(dns_servers: &'a [[u8; 4]]) -> Self {
+        Self {
+            dns_server_list_count: dns_servers.len(),
+            dns_server_list: dns_servers.as_ptr(),
+            use_default_setting: true,
+            enable_dns_cache: true,
+            protocol: 17,
+            station_ip: [0; 4],
+            subnet_mask: [0; 4],
+            local_port: 0,
+            retry_count: 3,
+            retry_interval: 2,
+            _marker: PhantomData,
+        }
+    }
+
+    /// DNS servers used for lookups.
+    pub fn dns_servers(&self) -> &'a [[u8; 4]] {
+        if self.dns_server_list.is_null() {
+            &[]
+        } else {
+            unsafe { slice::from_raw_parts(self.dns_server_list, self.dns_server_list_count) }
+        }
+    }
+}
+
+/// Token used to track an asynchronous lookup
+/// (`EFI_DNS4_COMPLETION_TOKEN`).
+#[repr(C)]
+struct CompletionToken {
+    event: Event,
+    status: Status,
+    retry_count: u32,
+    retry_interval: u32,
+    rsp_data: *mut HostToAddrData,
+}
+
+/// Result of a host name lookup (`DNS_HOST_TO_ADDR_DATA`).
+#[repr(C)]
+struct HostToAddrData {
+    ip_count: u32,
+    ip_list: *mut [u8; 4],
+}
+
+/// Host name lookup result allocated by the driver, which is freed when
+/// dropped.
+struct HostToAddrResponse<'boot> {
+    boot_services: &'boot BootServices,
+    data: *mut HostToAddrData,
+}
+
+impl HostToAddrResponse<'_> {
+    fn addresses(&self) -> &[[u8; 4]] {
+        match unsafe { self.data.as_ref() } {
+            Some(data) if !data.ip_list.is_null() => unsafe {
+                slice::from_raw_parts(data.ip_list, data.ip_count as usize)
+            },
+            _ => &[],
+        }
+    }
+}
+
+impl Drop for HostToAddrResponse<'_> {
+    fn drop(&mut self) {
+        if let Some(data) = unsafe { self.data.as_ref() } {
+            if !data.ip_list.is_null() {
+                let _ = self.boot_services.free_pool(data.ip_list as *mut u8);
+            }
+            let _ = self.boot_services.free_pool(self.data as *mut u8);
+        }
+    }
+}
@@ -0,0 +1,179 @@
+//! IPv4 configuration protocol.
+
+use super::MacAddress;
+use crate::data_types::Align;
+use crate::proto::Protocol;
+use crate::result::Error;
+use crate::{unsafe_guid, CStr16, Completion, Result, Status};
+use core::ffi::c_void;
+use core::{mem, slice};
+
+newtype_enum! {
+    /// Type of configuration data accessed through [`Ip4Config2`].
+    pub enum Ip4Config2DataType: u32 => {
+        /// General information about the interface, as an
+        /// [`Ip4Config2InterfaceInfo`].
+        INTERFACE_INFO = 0,
+        /// The configuration policy, as an [`Ip4Config2Policy`].
+        POLICY = 1,
+        /// The station addresses configured manually.
+        MANUAL_ADDRESS = 2,
+        /// The gateway addresses configured manually.
+        GATEWAY = 3,
+        /// The DNS server addresses configured manually.
+        DNS_SERVER = 4,
+    }
+}
+
+newtype_enum! {
+    /// How the IPv4 configuration of an interface is obtained.
+    pub enum Ip4Config2Policy: u32 => {
+        /// The configuration is set manually.
+        STATIC = 0,
+        /// The configuration is obtained through DHCP.
+        DHCP = 1,
+    }
+}
+
+/// Gets and sets the IPv4 configuration of a network interface.
+#[repr(C)]
+#[unsafe_guid("5b446ed1-e30b-4faa-871a-3654eca36080")]
+#[derive(Protocol)]
+pub struct Ip4Config2 {
+    _set_data: usize,
+    get_data: extern "efiapi" fn(
+        this: &Ip4Config2,
+        data_type: Ip4Config2DataType,
+        data_size: &mut usize,
+        data: *mut c_void,
+    ) -> Status,
+    _register_data_notify: usize,
+    _unregister_data_notify: usize,
+}
+
+impl Ip4Config2 {
+    /// Read the configuration data of type `data_type` into `buffer`, and
+    /// return the part of `buffer` that was filled.
+    ///
+    /// # Errors
+    /// * `uefi::Status::BUFFER_TOO_SMALL`  `buffer` is too small; the required size is returned.
+    /// * `uefi::Status::NOT_READY`         The data is being configured and is not available yet.
+    /// * `uefi::Status::NOT_FOUND`         The data is not set.
+    pub fn get_data<'buf>(
+        &self,
+        data_type: Ip4Config2DataType,
+        buffer: &'buf mut [u8],
+    ) -> Result<&'buf [u8], Option<usize>> {
+        let mut size = buffer.len();
+        (self.get_data)(
+            self,
+            data_type,
+            &mut size,
+            buffer.as_mut_ptr() as *mut c_void,
+        )
+        .into_with(
+            || &buffer[..size],
+            |s| {
+                if s == Status::BUFFER_TOO_SMALL {
+                    Some(size)
+                } else {
+                    None
+                }
+            },
+        )
+    }
+
+    /// Read general information about the interface, including its station
+    /// address and route table, into `buffer`.
+    ///
+    /// The buffer must be correctly aligned for an `Ip4Config2InterfaceInfo`.
+    /// You can query the required alignment through the `Align` trait
+    /// (`<Ip4Config2InterfaceInfo as Align>::alignment()`).
+    ///
+    /// # Errors
+    /// * `uefi::Status::BUFFER_TOO_SMALL`  `buffer` is too small; the required size is returned.
+    /// * `uefi::Status::BAD_BUFFER_SIZE`   The data returned by the firmware is too short.
+    pub fn interface_info<'buf>(
+        &self,
+        buffer: &'buf mut [u8],
+    ) -> Result<&'buf Ip4Config2InterfaceInfo, Option<usize>> {
+        Ip4Config2InterfaceInfo::assert_aligned(buffer);
+
+        let (status, data) = self
+            .get_data(Ip4Config2DataType::INTERFACE_INFO, buffer)?
+            .split();
+        if data.len() < mem::size_of::<Ip4Config2InterfaceInfo>() {
+            return Err(Error::new(Status::BAD_BUFFER_SIZE, None));
+        }
+        let info = unsafe { &*(data.as_ptr() as *const Ip4Config2InterfaceInfo) };
+        Ok(Completion::new(status, info))
+    }
+
+    /// Get the configuration policy of the interface.
+    pub fn policy(&self) -> Result<Ip4Config2Policy> {
+        let mut policy = Ip4Config2Policy::STATIC;
+        let mut size = mem::size_of::<Ip4Config2Policy>();
+        (self.get_data)(
+            self,
+            Ip4Config2DataType::POLICY,
+            &mut size,
+            &mut policy as *mut Ip4Config2Policy as *mut c_void,
+        )
+        .into_with_val(|| policy)
+    }
+}
+
+/// General information about a network interface
+/// (`EFI_IP4_CONFIG2_INTERFACE_INFO`).
+#[derive(Debug)]
+#[repr(C)]
+pub struct Ip4Config2InterfaceInfo {
+    name: [u16; 32],
+    /// Interface type, as defined by IANA (1 for Ethernet).
+    pub if_type: u8,
+    /// Size of the hardware address, in bytes.
+    pub hw_address_size: u32,
+    /// Hardware address of the interface.
+    pub hw_address: MacAddress,
+    /// IPv4 address of the station.
+    pub station_address: [u8; 4],
+    /// Subnet mask of the station.
+    pub subnet_mask: [u8; 4],
+    route_table_size: u32,
+    route_table: *const Ip4RouteTableEntry,
+}
+
+impl Ip4Config2InterfaceInfo {
+    /// Name of the interface, or `None` if it is not a valid string.
+    pub fn name(&self) -> Option<&CStr16> {
+        let len = self.name.iter().position(|&c| c == 0)?;
+        CStr16::from_u16_with_nul(&self.name[..=len]).ok()
+    }
+
+    /// Routes of the interface.
+    pub fn route_table(&self) -> &[Ip4RouteTableEntry] {
+        if self.route_table.is_null() {
+            &[]
+        } else {
+            unsafe { slice::from_raw_parts(self.route_table, self.route_table_size as usize) }
+        }
+    }
+}
+
+impl Align for Ip4Config2InterfaceInfo {
+    fn alignment() -> usize {
+        mem::align_of::<Self>()
+    }
+}
+
+/// An entry of the IPv4 route table.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(C)]
+pub struct Ip4RouteTableEntry {
+    /// Address of the destination subnet.
+    pub subnet_address: [u8; 4],
+    /// Mask of the destination subnet.
+    pub subnet_mask: [u8; 4],
+    /// Address of the gateway, or 0.0.0.0 for a directly attached subnet.
+    pub gateway_address: [u8; 4],
+}
@@ -0,0 +1,41 @@
+//! Network access protocols.
+
+pub mod dns;
+pub mod ip4config2;
+pub mod pxe;
+pub mod snp;
+
+/// A hardware (MAC) address, padded to 32 bytes.
+///
+/// Only the first `hw_address_size` bytes reported by the network interface
+/// are significant; for Ethernet, that is 6 bytes.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct MacAddress(pub [u8; 32]);
+
+/// An IPv4 or IPv6 address, padded to 16 bytes.
+///
+/// Whether the address is an IPv4 or IPv6 address depends on the context in
+/// which it is used; an IPv4 address occupies the first 4 bytes.
+#[repr(C, align(4))]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct IpAddress(pub [u8; 16]);
+
+impl IpAddress {
+    /// Create an IPv4 address.
+    pub const fn new_v4(ip: [u8; 4]) -> Self {
+        Self([
+            ip[0], ip[1], ip[2], ip[3], 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ])
+    }
+
+    /// Create an IPv6 address.
+    pub const fn new_v6(ip: [u8; 16]) -> Self {
+        Self(ip)
+    }
+
+    /// Get the address as an IPv4 address, ignoring the padding.
+    pub const fn as_v4(&self) -> [u8; 4] {
+        [self.0[0], self.0[1], self.0[2], self.0[3]]
+    }
+}
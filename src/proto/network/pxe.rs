@@ -0,0 +1,225 @@
+//! PXE base code protocol, which provides DHCP and TFTP for network boot.
+
+use super::IpAddress;
+use crate::proto::Protocol;
+use crate::{unsafe_guid, CStr8, Result, Status};
+use core::ffi::c_void;
+use core::ptr;
+
+/// `mtftp` operation to get the size of a file.
+const TFTP_GET_FILE_SIZE: u32 = 1;
+/// `mtftp` operation to read a file.
+const TFTP_READ_FILE: u32 = 2;
+
+/// Provides DHCP, TFTP and other services used for network boot.
+///
+/// The protocol must be started with `start` before it can be used.
+#[repr(C)]
+#[unsafe_guid("03c4e603-ac28-11d3-9a2d-0090273fc14d")]
+#[derive(Protocol)]
+pub struct BaseCode {
+    revision: u64,
+    start: extern "efiapi" fn(this: &mut BaseCode, use_ipv6: bool) -> Status,
+    stop: extern "efiapi" fn(this: &mut BaseCode) -> Status,
+    dhcp: extern "efiapi" fn(this: &mut BaseCode, sort_offers: bool) -> Status,
+    _discover: usize,
+    mtftp: extern "efiapi" fn(
+        this: &mut BaseCode,
+        operation: u32,
+        buffer: *mut c_void,
+        overwrite: bool,
+        buffer_size: &mut u64,
+        block_size: *const usize,
+        server_ip: &IpAddress,
+        filename: *const u8,
+        info: *const c_void,
+        dont_use_buffer: bool,
+    ) -> Status,
+    _udp_write: usize,
+    _udp_read: usize,
+    _set_ip_filter: usize,
+    _arp: usize,
+    _set_parameters: usize,
+    _set_station_ip: usize,
+    _set_packets: usize,
+    mode: *const BaseCodeMode,
+}
+
+impl BaseCode {
+    /// Returns the current state and configuration of the protocol.
+    pub fn mode(&self) -> &BaseCodeMode {
+        unsafe { &*self.mode }
+    }
+
+    /// Enables the protocol, using IPv6 if `use_ipv6` is true and IPv4
+    /// otherwise.
+    ///
+    /// # Errors
+    /// * `uefi::Status::ALREADY_STARTED`  The protocol is already started.
+    /// * `uefi::Status::UNSUPPORTED`      IPv6 was requested but is not supported.
+    /// * `uefi::Status::DEVICE_ERROR`     The network device encountered an error.
+    pub fn start(&mut self, use_ipv6: bool) -> Result {
+        (self.start)(self, use_ipv6).into()
+    }
+
+    /// Disables the protocol.
+    ///
+    /// # Errors
+    /// * `uefi::Status::NOT_STARTED`   The protocol has not been started.
+    /// * `uefi::Status::DEVICE_ERROR`  The network device encountered an error.
+    pub fn stop(&mut self) -> Result {
+        (self.stop)(self).into()
+    }
+
+    /// Performs a DHCP exchange to obtain an IP address. On success, the
+    /// address is available as `mode().station_ip`.
+    ///
+    /// If `sort_offers` is true, all offers are collected and the best one is
+    /// selected; otherwise, the first valid offer is used.
+    ///
+    /// # Errors
+    /// * `uefi::Status::NOT_STARTED`  The protocol has not been started.
+    /// * `uefi::Status::TIMEOUT`      No valid offer was received.
+    /// * `uefi::Status::ABORTED`      The exchange was aborted by a callback.
+    pub fn dhcp(&mut self, sort_offers: bool) -> Result {
+        (self.dhcp)(self, sort_offers).into()
+    }
+
+    /// Gets the size of a file on a TFTP server.
+    ///
+    /// # Errors
+    /// * `uefi::Status::NOT_STARTED`  The protocol has not been started.
+    /// * `uefi::Status::TFTP_ERROR`   The server returned an error, for example because the file
+    ///   does not exist.
+    /// * `uefi::Status::TIMEOUT`      The server did not respond.
+    pub fn get_file_size(&mut self, server_ip: &IpAddress, filename: &CStr8) -> Result<u64> {
+        let mut buffer_size = 0;
+        (self.mtftp)(
+            self,
+            TFTP_GET_FILE_SIZE,
+            ptr::null_mut(),
+            false,
+            &mut buffer_size,
+            ptr::null(),
+            server_ip,
+            filename.as_ptr() as *const u8,
+            ptr::null(),
+            false,
+        )
+        .into_with_val(|| buffer_size)
+    }
+
+    /// Reads a file from a TFTP server into `buffer`, and returns the number
+    /// of bytes read.
+    ///
+    /// # Errors
+    /// * `uefi::Status::NOT_STARTED`       The protocol has not been started.
+    /// * `uefi::Status::BUFFER_TOO_SMALL`  `buffer` is too small; the file size is returned.
+    /// * `uefi::Status::TFTP_ERROR`        The server returned an error, for example because the
+    ///   file does not exist.
+    /// * `uefi::Status::TIMEOUT`           The server did not respond.
+    pub fn read_file(
+        &mut self,
+        server_ip: &IpAddress,
+        filename: &CStr8,
+        buffer: &mut [u8],
+    ) -> Result<usize, Option<usize>> {
+        let mut buffer_size = buffer.len() as u64;
+        (self.mtftp)(
+            self,
+            TFTP_READ_FILE,
+            buffer.as_mut_ptr() as *mut c_void,
+            false,
+            &mut buffer_size,
+            ptr::null(),
+            server_ip,
+            filename.as_ptr() as *const u8,
+            ptr::null(),
+            false,
+        )
+        .into_with(
+            || buffer_size as usize,
+            |s| {
+                if s == Status::BUFFER_TOO_SMALL {
+                    Some(buffer_size as usize)
+                } else {
+                    None
+                }
+            },
+        )
+    }
+}
+
+/// A raw DHCP or PXE packet (`EFI_PXE_BASE_CODE_PACKET`).
+#[repr(C, align(4))]
+#[derive(Clone, Copy)]
+pub struct Packet(pub [u8; 1472]);
+
+impl core::fmt::Debug for Packet {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.debug_struct("Packet").finish_non_exhaustive()
+    }
+}
+
+/// Current state and configuration of the PXE base code protocol.
+///
+/// Only the leading fields of `EFI_PXE_BASE_CODE_MODE` are exposed; the IP
+/// filter, ARP cache, route table and error packets that follow are not.
+#[derive(Debug)]
+#[repr(C)]
+pub struct BaseCodeMode {
+    /// Whether the protocol has been started.
+    pub started: bool,
+    /// Whether IPv6 is supported by the network interface.
+    pub ipv6_available: bool,
+    /// Whether IPv6 is supported by the protocol implementation.
+    pub ipv6_supported: bool,
+    /// Whether the protocol was started with IPv6.
+    pub using_ipv6: bool,
+    /// Whether Boot Integrity Services are supported.
+    pub bis_supported: bool,
+    /// Whether Boot Integrity Services were detected.
+    pub bis_detected: bool,
+    /// Whether ARP requests are sent automatically.
+    pub auto_arp: bool,
+    /// Whether the system GUID is sent in DHCP requests.
+    pub send_guid: bool,
+    /// Whether `dhcp_discover` is valid.
+    pub dhcp_discover_valid: bool,
+    /// Whether `dhcp_ack` is valid.
+    pub dhcp_ack_received: bool,
+    /// Whether `proxy_offer` is valid.
+    pub proxy_offer_received: bool,
+    /// Whether `pxe_discover` is valid.
+    pub pxe_discover_valid: bool,
+    /// Whether `pxe_reply` is valid.
+    pub pxe_reply_received: bool,
+    /// Whether `pxe_bis_reply` is valid.
+    pub pxe_bis_reply_received: bool,
+    /// Whether an ICMP error was received.
+    pub icmp_error_received: bool,
+    /// Whether a TFTP error was received.
+    pub tftp_error_received: bool,
+    /// Whether callbacks are made during operations.
+    pub make_callbacks: bool,
+    /// Time to live of outgoing packets.
+    pub ttl: u8,
+    /// Type of service of outgoing packets.
+    pub tos: u8,
+    /// IP address of the station.
+    pub station_ip: IpAddress,
+    /// Subnet mask of the station.
+    pub subnet_mask: IpAddress,
+    /// The last DHCP discover packet sent.
+    pub dhcp_discover: Packet,
+    /// The last DHCP ack packet received.
+    pub dhcp_ack: Packet,
+    /// The last proxy DHCP offer received.
+    pub proxy_offer: Packet,
+    /// The last PXE discover packet sent.
+    pub pxe_discover: Packet,
+    /// The last PXE reply received.
+    pub pxe_reply: Packet,
+    /// The last PXE BIS reply received.
+    pub pxe_bis_reply: Packet,
+}
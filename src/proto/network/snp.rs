@@ -0,0 +1,318 @@
+//! Simple Network Protocol, which provides raw access to a network interface.
+
+use super::MacAddress;
+use crate::proto::Protocol;
+use crate::{unsafe_guid, Event, Result, Status};
+use core::ffi::c_void;
+use core::ptr::{self, NonNull};
+
+/// Provides a packet-level interface to a network adapter.
+///
+/// The interface must be started with `start`, then initialized with
+/// `initialize`, before frames can be sent or received.
+#[repr(C)]
+#[unsafe_guid("a19832b9-ac25-11d3-9a2d-0090273fc14d")]
+#[derive(Protocol)]
+pub struct SimpleNetwork {
+    revision: u64,
+    start: extern "efiapi" fn(this: &SimpleNetwork) -> Status,
+    stop: extern "efiapi" fn(this: &SimpleNetwork) -> Status,
+    initialize: extern "efiapi" fn(
+        this: &SimpleNetwork,
+        extra_rx_buffer_size: usize,
+        extra_tx_buffer_size: usize,
+    ) -> Status,
+    reset: extern "efiapi" fn(this: &SimpleNetwork, extended_verification: bool) -> Status,
+    shutdown: extern "efiapi" fn(this: &SimpleNetwork) -> Status,
+    _receive_filters: usize,
+    _station_address: usize,
+    _statistics: usize,
+    _mcast_ip_to_mac: usize,
+    _nv_data: usize,
+    get_status: extern "efiapi" fn(
+        this: &SimpleNetwork,
+        interrupt_status: *mut u32,
+        tx_buf: *mut *mut c_void,
+    ) -> Status,
+    transmit: extern "efiapi" fn(
+        this: &SimpleNetwork,
+        header_size: usize,
+        buffer_size: usize,
+        buffer: *const c_void,
+        src_addr: *const MacAddress,
+        dest_addr: *const MacAddress,
+        protocol: *const u16,
+    ) -> Status,
+    receive: extern "efiapi" fn(
+        this: &SimpleNetwork,
+        header_size: *mut usize,
+        buffer_size: *mut usize,
+        buffer: *mut c_void,
+        src_addr: *mut MacAddress,
+        dest_addr: *mut MacAddress,
+        protocol: *mut u16,
+    ) -> Status,
+    wait_for_packet: Event,
+    mode: *const NetworkMode,
+}
+
+impl SimpleNetwork {
+    /// Returns the current state and properties of the network interface.
+    pub fn mode(&self) -> &NetworkMode {
+        unsafe { &*self.mode }
+    }
+
+    /// Changes the state of the network interface from stopped to started.
+    ///
+    /// # Errors
+    /// * `uefi::Status::ALREADY_STARTED`  The network interface is already started.
+    /// * `uefi::Status::DEVICE_ERROR`     The command could not be sent to the network interface.
+    pub fn start(&mut self) -> Result {
+        (self.start)(self).into()
+    }
+
+    /// Changes the state of the network interface from started to stopped.
+    ///
+    /// # Errors
+    /// * `uefi::Status::NOT_STARTED`   The network interface has not been started.
+    /// * `uefi::Status::DEVICE_ERROR`  The command could not be sent to the network interface.
+    pub fn stop(&mut self) -> Result {
+        (self.stop)(self).into()
+    }
+
+    /// Resets the network adapter and allocates the transmit and receive
+    /// buffers. The interface must have been started.
+    ///
+    /// `extra_rx_buffer_size` and `extra_tx_buffer_size` request additional
+    /// buffer space beyond the driver's defaults; pass 0 if unsure.
+    ///
+    /// # Errors
+    /// * `uefi::Status::NOT_STARTED`       The network interface has not been started.
+    /// * `uefi::Status::OUT_OF_RESOURCES`  The buffers could not be allocated.
+    /// * `uefi::Status::DEVICE_ERROR`      The command could not be sent to the network interface.
+    pub fn initialize(
+        &mut self,
+        extra_rx_buffer_size: usize,
+        extra_tx_buffer_size: usize,
+    ) -> Result {
+        (self.initialize)(self, extra_rx_buffer_size, extra_tx_buffer_size).into()
+    }
+
+    /// Resets the network adapter and reinitializes it with the parameters
+    /// passed to `initialize`.
+    ///
+    /// # Errors
+    /// * `uefi::Status::NOT_STARTED`   The network interface has not been started.
+    /// * `uefi::Status::DEVICE_ERROR`  The command could not be sent to the network interface.
+    pub fn reset(&mut self, extended_verification: bool) -> Result {
+        (self.reset)(self, extended_verification).into()
+    }
+
+    /// Resets the network adapter and leaves it in a state that is safe for
+    /// another driver to initialize. The interface goes back to the started
+    /// state.
+    ///
+    /// # Errors
+    /// * `uefi::Status::NOT_STARTED`   The network interface has not been started.
+    /// * `uefi::Status::DEVICE_ERROR`  The command could not be sent to the network interface.
+    pub fn shutdown(&mut self) -> Result {
+        (self.shutdown)(self).into()
+    }
+
+    /// Queues a frame to be sent on the network.
+    ///
+    /// If `header_size` is 0, `buffer` must contain a complete frame,
+    /// including the media header. Otherwise, `header_size` must be equal to
+    /// `mode().media_header_size`, and the driver fills in the media header
+    /// at the start of `buffer` from `src_addr`, `dest_addr` and `protocol`.
+    /// `src_addr` defaults to the current station address if `None`;
+    /// `dest_addr` and `protocol` are required in that case.
+    ///
+    /// This function returns as soon as the frame has been queued. Use
+    /// `get_recycled_transmit_buffer()` to find out when the driver is done
+    /// with `buffer`.
+    ///
+    /// # Safety
+    /// `buffer` must stay valid and must not be modified until it has been
+    /// returned by `get_recycled_transmit_buffer()`.
+    ///
+    /// # Errors
+    /// * `uefi::Status::NOT_STARTED`        The network interface has not been started.
+    /// * `uefi::Status::NOT_READY`          The network interface is too busy to accept the frame.
+    /// * `uefi::Status::BUFFER_TOO_SMALL`   `buffer` is too small for the media header.
+    /// * `uefi::Status::INVALID_PARAMETER`  `dest_addr` or `protocol` is missing for a header.
+    /// * `uefi::Status::DEVICE_ERROR`       The command could not be sent to the network interface.
+    pub unsafe fn transmit(
+        &mut self,
+        header_size: usize,
+        buffer: &[u8],
+        src_addr: Option<MacAddress>,
+        dest_addr: Option<MacAddress>,
+        protocol: Option<u16>,
+    ) -> Result {
+        (self.transmit)(
+            self,
+            header_size,
+            buffer.len(),
+            buffer.as_ptr() as *const c_void,
+            opt_ptr(&src_addr),
+            opt_ptr(&dest_addr),
+            opt_ptr(&protocol),
+        )
+        .into()
+    }
+
+    /// Returns a transmit buffer that the driver has finished with, if any.
+    ///
+    /// Each buffer passed to `transmit()` is returned here exactly once.
+    /// Buffers may be recycled in any order, so the returned pointer should
+    /// be compared against all outstanding buffers.
+    ///
+    /// # Errors
+    /// * `uefi::Status::NOT_STARTED`   The network interface has not been started.
+    /// * `uefi::Status::DEVICE_ERROR`  The command could not be sent to the network interface.
+    pub fn get_recycled_transmit_buffer(&mut self) -> Result<Option<NonNull<u8>>> {
+        let mut tx_buf = ptr::null_mut();
+        (self.get_status)(self, ptr::null_mut(), &mut tx_buf)
+            .into_with_val(|| NonNull::new(tx_buf as *mut u8))
+    }
+
+    /// Receives a frame from the network into `buffer`.
+    ///
+    /// Returns `None` if no frame has been received. Otherwise, the frame,
+    /// including its media header, is written to the start of `buffer`, and
+    /// its length and addresses are returned in a `ReceivedFrame`.
+    ///
+    /// This function does not block. Use `wait_for_packet_event()` with
+    /// `BootServices::wait_for_event()` to wait for a frame.
+    ///
+    /// # Errors
+    /// * `uefi::Status::NOT_STARTED`       The network interface has not been started.
+    /// * `uefi::Status::BUFFER_TOO_SMALL`  `buffer` is too small; the required size is returned.
+    /// * `uefi::Status::DEVICE_ERROR`      The command could not be sent to the network interface.
+    pub fn receive(&mut self, buffer: &mut [u8]) -> Result<Option<ReceivedFrame>, Option<usize>> {
+        let mut header_size = 0;
+        let mut buffer_size = buffer.len();
+        let mut src_addr = MacAddress::default();
+        let mut dest_addr = MacAddress::default();
+        let mut protocol = 0;
+
+        let status = (self.receive)(
+            self,
+            &mut header_size,
+            &mut buffer_size,
+            buffer.as_mut_ptr() as *mut c_void,
+            &mut src_addr,
+            &mut dest_addr,
+            &mut protocol,
+        );
+        match status {
+            Status::NOT_READY => Ok(None.into()),
+            status => status.into_with(
+                || {
+                    Some(ReceivedFrame {
+                        header_size,
+                        len: buffer_size,
+                        src_addr,
+                        dest_addr,
+                        protocol,
+                    })
+                },
+                |s| {
+                    if s == Status::BUFFER_TOO_SMALL {
+                        Some(buffer_size)
+                    } else {
+                        None
+                    }
+                },
+            ),
+        }
+    }
+
+    /// Event to be used with `BootServices::wait_for_event()` in order to
+    /// wait for a frame to be received.
+    pub fn wait_for_packet_event(&self) -> &Event {
+        &self.wait_for_packet
+    }
+}
+
+/// Convert an optional value to a pointer which is null if the value is
+/// `None`.
+fn opt_ptr<T>(value: &Option<T>) -> *const T {
+    match value {
+        Some(value) => value,
+        None => ptr::null(),
+    }
+}
+
+/// Information about a frame returned by [`SimpleNetwork::receive`].
+#[derive(Clone, Copy, Debug)]
+pub struct ReceivedFrame {
+    /// Size of the media header at the start of the frame.
+    pub header_size: usize,
+    /// Size of the whole frame, including the media header.
+    pub len: usize,
+    /// Source hardware address.
+    pub src_addr: MacAddress,
+    /// Destination hardware address.
+    pub dest_addr: MacAddress,
+    /// Protocol type from the media header, such as an Ethertype.
+    pub protocol: u16,
+}
+
+newtype_enum! {
+    /// State of a network interface.
+    pub enum NetworkState: u32 => {
+        /// The interface is stopped.
+        STOPPED = 0,
+        /// The interface has been started, but not initialized.
+        STARTED = 1,
+        /// The interface is initialized and can send and receive frames.
+        INITIALIZED = 2,
+    }
+}
+
+/// Current state and properties of a network interface.
+#[derive(Debug)]
+#[repr(C)]
+pub struct NetworkMode {
+    /// State of the interface.
+    pub state: NetworkState,
+    /// Size of the hardware address, in bytes.
+    pub hw_address_size: u32,
+    /// Size of the media header, in bytes.
+    pub media_header_size: u32,
+    /// Maximum size of a frame's payload, excluding the media header.
+    pub max_packet_size: u32,
+    /// Size of the non-volatile memory of the device, in bytes.
+    pub nv_ram_size: u32,
+    /// Access granularity of the non-volatile memory, in bytes.
+    pub nv_ram_access_size: u32,
+    /// Receive filters supported by the interface.
+    pub receive_filter_mask: u32,
+    /// Receive filters currently enabled.
+    pub receive_filter_setting: u32,
+    /// Maximum number of multicast addresses the filter can hold.
+    pub max_mcast_filter_count: u32,
+    /// Number of multicast addresses in `mcast_filter`.
+    pub mcast_filter_count: u32,
+    /// Multicast addresses currently accepted by the receive filter.
+    pub mcast_filter: [MacAddress; 16],
+    /// Current hardware address of the interface.
+    pub current_address: MacAddress,
+    /// Broadcast address of the network.
+    pub broadcast_address: MacAddress,
+    /// Hardware address burned into the interface.
+    pub permanent_address: MacAddress,
+    /// Interface type, as defined by IANA (1 for Ethernet).
+    pub if_type: u8,
+    /// Whether the hardware address can be changed.
+    pub mac_address_changeable: bool,
+    /// Whether the interface can queue more than one transmitted frame.
+    pub multiple_tx_supported: bool,
+    /// Whether the interface can report if media is present.
+    pub media_present_supported: bool,
+    /// Whether media is present. Only valid if `media_present_supported` is
+    /// set.
+    pub media_present: bool,
+}
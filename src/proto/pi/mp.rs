@@ -151,6 +151,16 @@ impl MpServices {
     }
 
     /// Executes provided function on all APs in blocking mode.
+    ///
+    /// If `single_thread` is true, the APs run the function one after the
+    /// other; otherwise, they run it simultaneously. This function must be
+    /// called from the BSP.
+    ///
+    /// # Errors
+    /// * `uefi::Status::DEVICE_ERROR`  The caller processor is an AP.
+    /// * `uefi::Status::NOT_STARTED`   No enabled APs exist in the system.
+    /// * `uefi::Status::NOT_READY`     Some APs are busy executing another function.
+    /// * `uefi::Status::TIMEOUT`       The APs did not finish before `timeout` expired.
     pub fn startup_all_aps(
         &self,
         single_thread: bool,
@@ -176,6 +186,14 @@ impl MpServices {
     }
 
     /// Executes provided function on a specific AP in blocking mode.
+    ///
+    /// This function must be called from the BSP.
+    ///
+    /// # Errors
+    /// * `uefi::Status::DEVICE_ERROR`       The caller processor is an AP.
+    /// * `uefi::Status::NOT_FOUND`          No processor has the number `processor_number`.
+    /// * `uefi::Status::INVALID_PARAMETER`  `processor_number` is the BSP or a disabled AP.
+    /// * `uefi::Status::TIMEOUT`            The AP did not finish before `timeout` expired.
     pub fn startup_this_ap(
         &self,
         processor_number: usize,
@@ -0,0 +1,79 @@
+//! Random number generator protocol.
+
+use crate::proto::Protocol;
+use crate::{guid, unsafe_guid, Guid, Result, Status};
+use core::{mem, ptr};
+
+/// Provides access to the random number generators of the platform.
+#[repr(C)]
+#[unsafe_guid("3152bca5-eade-433d-862e-c01cdc291f44")]
+#[derive(Protocol)]
+pub struct Rng {
+    get_info: unsafe extern "efiapi" fn(
+        this: &Rng,
+        algorithm_list_size: *mut usize,
+        algorithm_list: *mut Guid,
+    ) -> Status,
+    get_rng: unsafe extern "efiapi" fn(
+        this: &Rng,
+        algorithm: *const Guid,
+        value_length: usize,
+        value: *mut u8,
+    ) -> Status,
+}
+
+impl Rng {
+    /// Retrieve the list of algorithms supported by the generator.
+    ///
+    /// The algorithms are written to `algorithm_buf`, and the part of it that
+    /// was filled is returned. If the buffer is too small, a
+    /// `BUFFER_TOO_SMALL` error is returned, along with the number of
+    /// algorithms that the buffer must be able to hold.
+    pub fn get_info<'buf>(
+        &self,
+        algorithm_buf: &'buf mut [Guid],
+    ) -> Result<&'buf [Guid], Option<usize>> {
+        let mut size = mem::size_of_val(algorithm_buf);
+        unsafe { (self.get_info)(self, &mut size, algorithm_buf.as_mut_ptr()) }.into_with(
+            || &algorithm_buf[..size / mem::size_of::<Guid>()],
+            |s| {
+                if s == Status::BUFFER_TOO_SMALL {
+                    Some(size / mem::size_of::<Guid>())
+                } else {
+                    None
+                }
+            },
+        )
+    }
+
+    /// Fill `buffer` with random bytes.
+    ///
+    /// If `algorithm` is `None`, the generator's default algorithm is used.
+    /// Otherwise, it must be one of the algorithms returned by `get_info`,
+    /// such as [`ALGORITHM_RAW`].
+    pub fn get_rng(&self, algorithm: Option<Guid>, buffer: &mut [u8]) -> Result {
+        let algorithm_ptr = match &algorithm {
+            Some(algorithm) => algorithm as *const Guid,
+            None => ptr::null(),
+        };
+        unsafe { (self.get_rng)(self, algorithm_ptr, buffer.len(), buffer.as_mut_ptr()) }.into()
+    }
+}
+
+/// NIST SP 800-90 Hash_DRBG using SHA-256.
+pub const ALGORITHM_SP800_90_HASH_256: Guid = guid!("a7af67cb-603b-4d42-ba21-70bfb6293f96");
+
+/// NIST SP 800-90 HMAC_DRBG using SHA-256.
+pub const ALGORITHM_SP800_90_HMAC_256: Guid = guid!("c5149b43-ae85-4f53-9982-b94335d3a9e7");
+
+/// NIST SP 800-90 CTR_DRBG using AES-256.
+pub const ALGORITHM_SP800_90_CTR_256: Guid = guid!("44f0de6e-4d8c-4045-a8c7-4dd168856b9e");
+
+/// ANSI X9.31 using 3DES.
+pub const ALGORITHM_X9_31_3DES: Guid = guid!("63c4785a-ca34-4012-a3c8-0b6a324f5546");
+
+/// ANSI X9.31 using AES.
+pub const ALGORITHM_X9_31_AES: Guid = guid!("acd03321-777e-4d3d-b1c8-20cfd88820c9");
+
+/// Raw entropy from a hardware noise source, without conditioning.
+pub const ALGORITHM_RAW: Guid = guid!("e43176d7-b6e8-4827-b784-7ffdc4b68561");
@@ -0,0 +1,137 @@
+//! Security protocols.
+
+use crate::proto::Protocol;
+use crate::{guid, unsafe_guid, Guid, Result, Status};
+
+/// Maximum size of a digest returned by the `Hash2` protocol, which is the
+/// size of a SHA-512 digest.
+pub const MAX_HASH_SIZE: usize = 64;
+
+/// Storage for the digest computed by the firmware (`EFI_HASH2_OUTPUT`).
+#[repr(C)]
+struct Hash2Output([u8; MAX_HASH_SIZE]);
+
+/// Computes hashes using the algorithms supported by the firmware.
+///
+/// Algorithms are identified by GUIDs such as [`HASH_ALGORITHM_SHA256`]. If
+/// an algorithm is not supported, the methods return `UNSUPPORTED`.
+#[repr(C)]
+#[unsafe_guid("55b1d734-c5e1-49db-9647-b16afb0e305b")]
+#[derive(Protocol)]
+pub struct Hash2 {
+    get_hash_size:
+        unsafe extern "efiapi" fn(this: &Hash2, algorithm: &Guid, hash_size: &mut usize) -> Status,
+    hash: unsafe extern "efiapi" fn(
+        this: &mut Hash2,
+        algorithm: &Guid,
+        message: *const u8,
+        message_size: usize,
+        hash: *mut Hash2Output,
+    ) -> Status,
+    hash_init: unsafe extern "efiapi" fn(this: &mut Hash2, algorithm: &Guid) -> Status,
+    hash_update: unsafe extern "efiapi" fn(
+        this: &mut Hash2,
+        message: *const u8,
+        message_size: usize,
+    ) -> Status,
+    hash_final: unsafe extern "efiapi" fn(this: &mut Hash2, hash: *mut Hash2Output) -> Status,
+}
+
+impl Hash2 {
+    /// Get the size in bytes of the digests computed by `algorithm`.
+    ///
+    /// # Errors
+    /// * `uefi::Status::UNSUPPORTED`   The algorithm is not supported.
+    pub fn get_hash_size(&self, algorithm: Guid) -> Result<usize> {
+        let mut hash_size = 0;
+        unsafe { (self.get_hash_size)(self, &algorithm, &mut hash_size) }
+            .into_with_val(|| hash_size)
+    }
+
+    /// Compute the digest of `data` using `algorithm`, and write it to `out`.
+    ///
+    /// Only the first `get_hash_size(algorithm)` bytes of `out` are written.
+    ///
+    /// # Errors
+    /// * `uefi::Status::UNSUPPORTED`       The algorithm is not supported.
+    /// * `uefi::Status::BUFFER_TOO_SMALL`  `out` is shorter than the digest.
+    /// * `uefi::Status::OUT_OF_RESOURCES`  The digest could not be computed.
+    pub fn hash(&mut self, algorithm: Guid, data: &[u8], out: &mut [u8]) -> Result {
+        let hash_size = self.get_hash_size(algorithm)?.log();
+        if out.len() < hash_size {
+            return Status::BUFFER_TOO_SMALL.into();
+        }
+
+        let mut output = Hash2Output([0; MAX_HASH_SIZE]);
+        unsafe { (self.hash)(self, &algorithm, data.as_ptr(), data.len(), &mut output) }
+            .into_with_val(|| out[..hash_size].copy_from_slice(&output.0[..hash_size]))
+    }
+
+    /// Start computing a digest using `algorithm`, for data which is passed
+    /// in pieces to `hash_update`.
+    ///
+    /// The returned `HashState` records the size of the digest, and must be
+    /// passed to `hash_final`.
+    ///
+    /// # Errors
+    /// * `uefi::Status::UNSUPPORTED`       The algorithm is not supported.
+    /// * `uefi::Status::ALREADY_STARTED`   A digest is already being computed.
+    pub fn hash_init(&mut self, algorithm: Guid) -> Result<HashState> {
+        let hash_size = self.get_hash_size(algorithm)?.log();
+        unsafe { (self.hash_init)(self, &algorithm) }.into_with_val(|| HashState { hash_size })
+    }
+
+    /// Add `data` to the digest started with `hash_init`.
+    ///
+    /// # Errors
+    /// * `uefi::Status::NOT_READY`         `hash_init` was not called.
+    pub fn hash_update(&mut self, data: &[u8]) -> Result {
+        unsafe { (self.hash_update)(self, data.as_ptr(), data.len()) }.into()
+    }
+
+    /// Finish the digest started with `hash_init`, and write it to `out`.
+    ///
+    /// Only the first `state.hash_size()` bytes of `out` are written.
+    ///
+    /// # Errors
+    /// * `uefi::Status::NOT_READY`         `hash_init` was not called.
+    /// * `uefi::Status::BUFFER_TOO_SMALL`  `out` is shorter than the digest.
+    pub fn hash_final(&mut self, state: HashState, out: &mut [u8]) -> Result {
+        let hash_size = state.hash_size;
+        if out.len() < hash_size {
+            return Status::BUFFER_TOO_SMALL.into();
+        }
+
+        let mut output = Hash2Output([0; MAX_HASH_SIZE]);
+        unsafe { (self.hash_final)(self, &mut output) }
+            .into_with_val(|| out[..hash_size].copy_from_slice(&output.0[..hash_size]))
+    }
+}
+
+/// A digest being computed by `Hash2::hash_init` and `Hash2::hash_update`.
+#[derive(Debug)]
+pub struct HashState {
+    hash_size: usize,
+}
+
+impl HashState {
+    /// Size in bytes of the digest that `Hash2::hash_final` will write.
+    pub fn hash_size(&self) -> usize {
+        self.hash_size
+    }
+}
+
+/// SHA-1, which is considered insecure.
+pub const HASH_ALGORITHM_SHA1: Guid = guid!("2ae9d80f-3fb2-4095-b7b1-e93157b946b6");
+
+/// SHA-224.
+pub const HASH_ALGORITHM_SHA224: Guid = guid!("8df01a06-9bd5-4bf7-b021-db4fd9ccf45b");
+
+/// SHA-256.
+pub const HASH_ALGORITHM_SHA256: Guid = guid!("51aa59de-fdf2-4ea3-bc63-875fb7842ee9");
+
+/// SHA-384.
+pub const HASH_ALGORITHM_SHA384: Guid = guid!("efa96432-de33-4dd2-aee6-328c33df777a");
+
+/// SHA-512.
+pub const HASH_ALGORITHM_SHA512: Guid = guid!("caa4381e-750c-4770-b870-7a23b4e42130");
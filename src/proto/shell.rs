@@ -0,0 +1,69 @@
+//! UEFI Shell protocol.
+
+use crate::proto::Protocol;
+use crate::{unsafe_guid, CStr16, Char16, Result, Status};
+use core::ptr;
+
+/// Provides access to the services of the UEFI Shell, such as environment
+/// variables.
+///
+/// This protocol is only available when running under the UEFI Shell. The
+/// command-line arguments of an application are not provided by this
+/// protocol, but by [`ShellParameters`] on the image handle.
+///
+/// [`ShellParameters`]: crate::proto::shell_params::ShellParameters
+#[repr(C)]
+#[unsafe_guid("6302d008-7f9b-4f30-87ac-60c9fef5da4e")]
+#[derive(Protocol)]
+pub struct Shell {
+    _execute: usize,
+    get_env: extern "efiapi" fn(name: *const Char16) -> *const Char16,
+    set_env:
+        extern "efiapi" fn(name: *const Char16, value: *const Char16, volatile: bool) -> Status,
+    _get_alias: usize,
+    _set_alias: usize,
+    _get_help_text: usize,
+    _get_device_path_from_map: usize,
+    _get_map_from_device_path: usize,
+    _get_device_path_from_file_path: usize,
+    _get_file_path_from_device_path: usize,
+    _set_map: usize,
+    get_cur_dir: extern "efiapi" fn(file_system_mapping: *const Char16) -> *const Char16,
+    // The remaining functions are not wrapped yet.
+}
+
+impl Shell {
+    /// Get the value of the environment variable `name`, or `None` if it
+    /// is not set.
+    pub fn get_env(&self, name: &CStr16) -> Option<&CStr16> {
+        let value = (self.get_env)(name.as_ptr());
+        if value.is_null() {
+            None
+        } else {
+            Some(unsafe { CStr16::from_ptr(value) })
+        }
+    }
+
+    /// Set the environment variable `name` to `value`. An empty `value`
+    /// deletes the variable.
+    ///
+    /// Volatile variables are lost when the shell exits, while non-volatile
+    /// ones are saved to UEFI variables.
+    ///
+    /// # Errors
+    /// * `uefi::Status::ACCESS_DENIED`  The variable is read-only, or has a different volatility.
+    pub fn set_env(&mut self, name: &CStr16, value: &CStr16, volatile: bool) -> Result {
+        (self.set_env)(name.as_ptr(), value.as_ptr(), volatile).into()
+    }
+
+    /// Get the current directory of the current file system, or `None` if
+    /// there is no current file system.
+    pub fn get_cur_dir(&self) -> Option<&CStr16> {
+        let dir = (self.get_cur_dir)(ptr::null());
+        if dir.is_null() {
+            None
+        } else {
+            Some(unsafe { CStr16::from_ptr(dir) })
+        }
+    }
+}
@@ -0,0 +1,88 @@
+//! UEFI Shell parameters protocol.
+
+use crate::proto::Protocol;
+use crate::{unsafe_guid, CStr16, Char16};
+use core::ffi::c_void;
+use core::ptr::NonNull;
+use core::slice;
+
+/// Provides the command-line arguments and standard file handles of an
+/// application launched from the UEFI Shell.
+///
+/// This protocol is installed on the image handle of the application, and
+/// can be used without the full [`Shell`] protocol.
+///
+/// [`Shell`]: crate::proto::shell::Shell
+#[repr(C)]
+#[unsafe_guid("752f3136-4e16-4fdc-a22a-e5f46812f4ca")]
+#[derive(Protocol)]
+pub struct ShellParameters {
+    argv: *const *const Char16,
+    argc: usize,
+    std_in: Option<ShellFileHandle>,
+    std_out: Option<ShellFileHandle>,
+    std_err: Option<ShellFileHandle>,
+}
+
+impl ShellParameters {
+    /// Returns an iterator over the command-line arguments. The first
+    /// argument is the name of the application.
+    pub fn args(&self) -> ShellArgsIter<'_> {
+        let argv = if self.argv.is_null() {
+            &[]
+        } else {
+            unsafe { slice::from_raw_parts(self.argv, self.argc) }
+        };
+        ShellArgsIter { inner: argv.iter() }
+    }
+
+    /// Handle of the standard input of the application.
+    pub fn stdin(&self) -> Option<ShellFileHandle> {
+        self.std_in
+    }
+
+    /// Handle of the standard output of the application.
+    pub fn stdout(&self) -> Option<ShellFileHandle> {
+        self.std_out
+    }
+
+    /// Handle of the standard error of the application.
+    pub fn stderr(&self) -> Option<ShellFileHandle> {
+        self.std_err
+    }
+}
+
+/// Opaque handle to a file opened by the UEFI Shell.
+#[derive(Clone, Copy, Debug)]
+#[repr(transparent)]
+pub struct ShellFileHandle(NonNull<c_void>);
+
+impl ShellFileHandle {
+    /// Get the raw pointer of this handle.
+    pub fn as_ptr(self) -> *mut c_void {
+        self.0.as_ptr()
+    }
+}
+
+/// Iterator over the command-line arguments of an application, returned by
+/// [`ShellParameters::args`].
+#[derive(Debug)]
+pub struct ShellArgsIter<'a> {
+    inner: slice::Iter<'a, *const Char16>,
+}
+
+impl<'a> Iterator for ShellArgsIter<'a> {
+    type Item = &'a CStr16;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner
+            .next()
+            .map(|&arg| unsafe { CStr16::from_ptr(arg) })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl ExactSizeIterator for ShellArgsIter<'_> {}
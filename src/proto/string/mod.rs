@@ -0,0 +1,7 @@
+//! String protocols.
+//!
+//! The protocols provide some features not present in the Rust standard library,
+//! such as locale-aware string comparisons.
+
+mod unicode_collation;
+pub use self::unicode_collation::{StrConversionError, UnicodeCollation};
@@ -0,0 +1,132 @@
+//! Unicode collation protocol.
+//!
+//! This protocol is used in the boot services environment to perform
+//! lexical comparison functions on Unicode strings for given languages.
+
+use crate::proto::Protocol;
+use crate::{unsafe_guid, CStr16, CStr8, Char16, Char8};
+use core::cmp::Ordering;
+
+/// Provides locale-aware case folding, comparison and pattern matching of
+/// strings, and conversions between strings and FAT file names.
+#[repr(C)]
+#[unsafe_guid("a4c751fc-23ae-4c3e-92e9-4964cf63f349")]
+#[derive(Protocol)]
+pub struct UnicodeCollation {
+    stri_coll: extern "efiapi" fn(this: &Self, s1: *const Char16, s2: *const Char16) -> isize,
+    metai_match:
+        extern "efiapi" fn(this: &Self, string: *const Char16, pattern: *const Char16) -> bool,
+    str_lwr: extern "efiapi" fn(this: &Self, s: *mut Char16),
+    str_upr: extern "efiapi" fn(this: &Self, s: *mut Char16),
+    fat_to_str: extern "efiapi" fn(this: &Self, fat_size: usize, fat: *const Char8, s: *mut Char16),
+    str_to_fat:
+        extern "efiapi" fn(this: &Self, s: *const Char16, fat_size: usize, fat: *mut Char8) -> bool,
+    supported_languages: *const Char8,
+}
+
+impl UnicodeCollation {
+    /// Performs a case insensitive comparison of two strings.
+    pub fn stri_coll(&self, s1: &CStr16, s2: &CStr16) -> Ordering {
+        let order = (self.stri_coll)(self, s1.as_ptr(), s2.as_ptr());
+        order.cmp(&0)
+    }
+
+    /// Performs a case insensitive match of `s` against `pattern`, and
+    /// returns true if the whole string matches.
+    ///
+    /// The following syntax can be used in `pattern`:
+    /// * `*` matches 0 or more characters.
+    /// * `?` matches any one character.
+    /// * `[abc]` matches any character in the set.
+    /// * `[a-z]` matches any character in the range.
+    /// * Any other character matches itself.
+    pub fn metai_match(&self, s: &CStr16, pattern: &CStr16) -> bool {
+        (self.metai_match)(self, s.as_ptr(), pattern.as_ptr())
+    }
+
+    /// Converts the characters in `s` to lower case characters, writing
+    /// the result to `buf`.
+    pub fn str_lwr<'a>(
+        &self,
+        s: &CStr16,
+        buf: &'a mut [u16],
+    ) -> Result<&'a CStr16, StrConversionError> {
+        let buf = copy_with_nul(s, buf)?;
+        (self.str_lwr)(self, buf.as_mut_ptr() as *mut Char16);
+        Ok(unsafe { CStr16::from_u16_with_nul_unchecked(buf) })
+    }
+
+    /// Converts the characters in `s` to upper case characters, writing
+    /// the result to `buf`.
+    pub fn str_upr<'a>(
+        &self,
+        s: &CStr16,
+        buf: &'a mut [u16],
+    ) -> Result<&'a CStr16, StrConversionError> {
+        let buf = copy_with_nul(s, buf)?;
+        (self.str_upr)(self, buf.as_mut_ptr() as *mut Char16);
+        Ok(unsafe { CStr16::from_u16_with_nul_unchecked(buf) })
+    }
+
+    /// Converts the 8.3 FAT file name `fat` to a null-terminated string,
+    /// writing the result to `buf`.
+    pub fn fat_to_str<'a>(
+        &self,
+        fat: &CStr8,
+        buf: &'a mut [u16],
+    ) -> Result<&'a CStr16, StrConversionError> {
+        let fat_size = fat.to_bytes().len();
+        let buf = buf.get_mut(..=fat_size).ok_or(StrConversionError)?;
+        buf.fill(0);
+        (self.fat_to_str)(
+            self,
+            fat_size,
+            fat.as_ptr(),
+            buf.as_mut_ptr() as *mut Char16,
+        );
+
+        let len = buf.iter().position(|&c| c == 0).unwrap_or(fat_size);
+        Ok(unsafe { CStr16::from_u16_with_nul_unchecked(&buf[..=len]) })
+    }
+
+    /// Converts `s` to a null-terminated FAT file name, writing the result
+    /// to `buf`.
+    ///
+    /// Characters which are not valid in FAT file names are substituted. The
+    /// returned boolean is true if any substitution was made.
+    pub fn str_to_fat<'a>(
+        &self,
+        s: &CStr16,
+        buf: &'a mut [u8],
+    ) -> Result<(&'a CStr8, bool), StrConversionError> {
+        // The firmware does not null-terminate the result, and writes at
+        // most one byte per character.
+        let buf = buf.get_mut(..=s.len()).ok_or(StrConversionError)?;
+        buf.fill(0);
+        let substituted =
+            (self.str_to_fat)(self, s.as_ptr(), s.len(), buf.as_mut_ptr() as *mut Char8);
+
+        let len = buf.iter().position(|&c| c == 0).unwrap_or(s.len());
+        let fat = unsafe { CStr8::from_bytes_with_nul_unchecked(&buf[..=len]) };
+        Ok((fat, substituted))
+    }
+
+    /// Returns the languages supported by this protocol, as a
+    /// semicolon-separated list of RFC 4646 language codes.
+    pub fn supported_languages(&self) -> &CStr8 {
+        unsafe { CStr8::from_ptr(self.supported_languages) }
+    }
+}
+
+/// Copy `s`, including its null terminator, to the start of `buf`.
+fn copy_with_nul<'a>(s: &CStr16, buf: &'a mut [u16]) -> Result<&'a mut [u16], StrConversionError> {
+    let s = s.to_u16_slice_with_nul();
+    let buf = buf.get_mut(..s.len()).ok_or(StrConversionError)?;
+    buf.copy_from_slice(s);
+    Ok(buf)
+}
+
+/// Error returned by the [`UnicodeCollation`] conversion functions when the
+/// output buffer is too small.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct StrConversionError;
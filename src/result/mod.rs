@@ -29,6 +29,18 @@ pub use self::status::Status;
 pub type Result<Output = (), ErrData = ()> =
     core::result::Result<Completion<Output>, Error<ErrData>>;
 
+/// Converts the result of an entry function into the status returned to the
+/// firmware, logging the error if there is one
+///
+/// This is used by the code generated by the `entry` macro.
+#[doc(hidden)]
+pub fn entry_result_status<Output, ErrData: Debug>(result: Result<Output, ErrData>) -> Status {
+    if let Err(err) = &result {
+        log::error!("Entry function failed: {:?}", err);
+    }
+    result.status()
+}
+
 /// Extension trait for Result which helps dealing with UEFI's warnings
 pub trait ResultExt<Output, ErrData: Debug> {
     /// Extract the UEFI status from this result
@@ -37,6 +49,12 @@ pub trait ResultExt<Output, ErrData: Debug> {
     /// Ignore warnings, keeping a trace of them in the logs
     fn log_warning(self) -> core::result::Result<Output, Error<ErrData>>;
 
+    /// Ignore warnings silently
+    ///
+    /// Use this when a warning is expected and does not need to be reported,
+    /// such as `Status::WARN_UNKNOWN_GLYPH` when printing arbitrary text.
+    fn ignore_warning(self) -> core::result::Result<Output, Error<ErrData>>;
+
     /// Expect success without warnings, panic otherwise
     fn unwrap_success(self) -> Output;
 
@@ -53,6 +71,13 @@ pub trait ResultExt<Output, ErrData: Debug> {
     fn discard_errdata(self) -> Result<Output>;
 
     /// Treat warnings as errors
+    ///
+    /// Warnings are the non-error statuses other than `Status::SUCCESS`,
+    /// such as `Status::WARN_UNKNOWN_GLYPH`, `Status::WARN_DELETE_FAILURE`,
+    /// `Status::WARN_WRITE_FAILURE`, `Status::WARN_BUFFER_TOO_SMALL`,
+    /// `Status::WARN_STALE_DATA`, `Status::WARN_FILE_SYSTEM` and
+    /// `Status::WARN_RESET_REQUIRED`. They are turned into an error with the
+    /// default error data.
     fn warning_as_error(self) -> core::result::Result<Output, Error<ErrData>>
     where
         ErrData: Default;
@@ -70,6 +95,10 @@ impl<Output, ErrData: Debug> ResultExt<Output, ErrData> for Result<Output, ErrDa
         self.map(Completion::log)
     }
 
+    fn ignore_warning(self) -> core::result::Result<Output, Error<ErrData>> {
+        self.map(|completion| completion.split().1)
+    }
+
     fn unwrap_success(self) -> Output {
         self.unwrap().unwrap()
     }
@@ -104,3 +133,53 @@ impl<Output, ErrData: Debug> ResultExt<Output, ErrData> for Result<Output, ErrDa
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WARNINGS: [Status; 7] = [
+        Status::WARN_UNKNOWN_GLYPH,
+        Status::WARN_DELETE_FAILURE,
+        Status::WARN_WRITE_FAILURE,
+        Status::WARN_BUFFER_TOO_SMALL,
+        Status::WARN_STALE_DATA,
+        Status::WARN_FILE_SYSTEM,
+        Status::WARN_RESET_REQUIRED,
+    ];
+
+    #[test]
+    fn test_ignore_warning() {
+        let result: Result<u32> = Ok(Completion::new(Status::SUCCESS, 1));
+        assert_eq!(result.ignore_warning().unwrap(), 1);
+
+        for &warning in WARNINGS.iter() {
+            let result: Result<u32> = Ok(Completion::new(warning, 1));
+            assert_eq!(result.ignore_warning().unwrap(), 1);
+        }
+
+        let result: Result<u32> = Err(Status::NOT_FOUND.into());
+        assert_eq!(
+            result.ignore_warning().unwrap_err().status(),
+            Status::NOT_FOUND
+        );
+    }
+
+    #[test]
+    fn test_warning_as_error() {
+        let result: Result<u32> = Ok(Completion::new(Status::SUCCESS, 1));
+        assert_eq!(result.warning_as_error().unwrap(), 1);
+
+        for &warning in WARNINGS.iter() {
+            assert!(warning.is_warning());
+            let result: Result<u32> = Ok(Completion::new(warning, 1));
+            assert_eq!(result.warning_as_error().unwrap_err().status(), warning);
+        }
+
+        let result: Result<u32> = Err(Status::NOT_FOUND.into());
+        assert_eq!(
+            result.warning_as_error().unwrap_err().status(),
+            Status::NOT_FOUND
+        );
+    }
+}
@@ -126,6 +126,20 @@ impl Status {
         self.0 & ERROR_BIT != 0
     }
 
+    /// Converts this status code into a `core::result::Result`, which is
+    /// `Err(self)` for any status other than `SUCCESS`, warnings included.
+    ///
+    /// This is handy to propagate the status of raw firmware calls with `?`
+    /// in functions returning `core::result::Result<_, Status>`.
+    #[inline]
+    pub fn into_result(self) -> core::result::Result<(), Status> {
+        if self.is_success() {
+            Ok(())
+        } else {
+            Err(self)
+        }
+    }
+
     /// Converts this status code into a result with a given value.
     #[inline]
     #[allow(clippy::result_unit_err)]
@@ -175,6 +189,10 @@ impl From<Status> for Result<(), ()> {
 
 pub struct StatusResidual(NonZeroUsize);
 
+impl core::ops::Residual<Completion<()>> for StatusResidual {
+    type TryType = Status;
+}
+
 impl Try for Status {
     type Output = Completion<()>;
     type Residual = StatusResidual;
@@ -223,3 +241,53 @@ impl From<ucs2::Error> for Status {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_status_predicates() {
+        assert!(Status::SUCCESS.is_success());
+        assert!(!Status::SUCCESS.is_warning());
+        assert!(!Status::SUCCESS.is_error());
+
+        for &status in &[Status::WARN_UNKNOWN_GLYPH, Status::WARN_RESET_REQUIRED] {
+            assert!(!status.is_success());
+            assert!(status.is_warning());
+            assert!(!status.is_error());
+        }
+
+        for &status in &[
+            Status::LOAD_ERROR,
+            Status::BUFFER_TOO_SMALL,
+            Status::HTTP_ERROR,
+            Status(ERROR_BIT),
+        ] {
+            assert!(!status.is_success());
+            assert!(!status.is_warning());
+            assert!(status.is_error());
+        }
+    }
+
+    #[test]
+    fn test_status_into_result() {
+        assert_eq!(Status::SUCCESS.into_result(), Ok(()));
+        assert_eq!(
+            Status::WARN_UNKNOWN_GLYPH.into_result(),
+            Err(Status::WARN_UNKNOWN_GLYPH)
+        );
+        assert_eq!(Status::NOT_FOUND.into_result(), Err(Status::NOT_FOUND));
+    }
+
+    #[test]
+    fn test_status_into_error() {
+        let err: Error = Status::NOT_FOUND.into();
+        assert_eq!(err.status(), Status::NOT_FOUND);
+
+        let result: Result = Status::WARN_STALE_DATA.into();
+        assert_eq!(result.unwrap().status(), Status::WARN_STALE_DATA);
+        let result: Result = Status::DEVICE_ERROR.into();
+        assert_eq!(result.unwrap_err().status(), Status::DEVICE_ERROR);
+    }
+}
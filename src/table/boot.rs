@@ -13,7 +13,9 @@ use core::cell::UnsafeCell;
 use core::ffi::c_void;
 use core::fmt::{Debug, Formatter};
 use core::mem::{self, MaybeUninit};
+use core::ops::{Deref, DerefMut};
 use core::ptr::NonNull;
+use core::time::Duration;
 use core::{ptr, slice};
 
 /// Contains pointers to all of the boot services.
@@ -63,13 +65,26 @@ pub struct BootServices {
     check_event: unsafe extern "efiapi" fn(event: Event) -> Status,
 
     // Protocol handlers
-    install_protocol_interface: usize,
+    install_protocol_interface: unsafe extern "efiapi" fn(
+        handle: &mut Option<Handle>,
+        guid: &Guid,
+        interface_type: u32,
+        interface: *mut c_void,
+    ) -> Status,
     reinstall_protocol_interface: usize,
-    uninstall_protocol_interface: usize,
+    uninstall_protocol_interface: unsafe extern "efiapi" fn(
+        handle: Handle,
+        protocol: &Guid,
+        interface: *mut c_void,
+    ) -> Status,
     handle_protocol:
         extern "efiapi" fn(handle: Handle, proto: &Guid, out_proto: &mut *mut c_void) -> Status,
     _reserved: usize,
-    register_protocol_notify: usize,
+    register_protocol_notify: extern "efiapi" fn(
+        protocol: &Guid,
+        event: Event,
+        registration: *mut ProtocolSearchKey,
+    ) -> Status,
     locate_handle: unsafe extern "efiapi" fn(
         search_ty: i32,
         proto: *const Guid,
@@ -119,8 +134,17 @@ pub struct BootServices {
     ) -> Status,
 
     // Driver support services
-    connect_controller: usize,
-    disconnect_controller: usize,
+    connect_controller: unsafe extern "efiapi" fn(
+        controller: Handle,
+        driver_image: *const Option<Handle>,
+        remaining_device_path: *const DevicePath,
+        recursive: bool,
+    ) -> Status,
+    disconnect_controller: unsafe extern "efiapi" fn(
+        controller: Handle,
+        driver_image: Option<Handle>,
+        child: Option<Handle>,
+    ) -> Status,
 
     // Protocol open / close services
     open_protocol: extern "efiapi" fn(
@@ -145,7 +169,13 @@ pub struct BootServices {
         protocol_buffer: *mut *mut *const Guid,
         protocol_buffer_count: *mut usize,
     ) -> Status,
-    locate_handle_buffer: usize,
+    locate_handle_buffer: unsafe extern "efiapi" fn(
+        search_ty: i32,
+        proto: *const Guid,
+        key: *mut c_void,
+        no_handles: &mut usize,
+        buf: &mut *mut Handle,
+    ) -> Status,
     locate_protocol: extern "efiapi" fn(
         proto: &Guid,
         registration: *mut c_void,
@@ -196,24 +226,33 @@ impl BootServices {
 
     /// Allocates memory pages from the system.
     ///
-    /// UEFI OS loaders should allocate memory of the type `LoaderData`. An `u64`
-    /// is returned even on 32-bit platforms because some hardware configurations
-    /// like Intel PAE enable 64-bit physical addressing on a 32-bit processor.
+    /// UEFI OS loaders should allocate memory of the type `LoaderData`.
+    ///
+    /// The pages are freed when the returned `PageAllocation` is dropped. Use
+    /// `PageAllocation::into_raw` to keep them allocated, e.g. in order to
+    /// hand them over to an operating system.
     pub fn allocate_pages(
         &self,
         ty: AllocateType,
         mem_ty: MemoryType,
         count: usize,
-    ) -> Result<u64> {
+    ) -> Result<PageAllocation<'_>> {
         let (ty, mut addr) = match ty {
             AllocateType::AnyPages => (0, 0),
             AllocateType::MaxAddress(addr) => (1, addr as u64),
             AllocateType::Address(addr) => (2, addr as u64),
         };
-        (self.allocate_pages)(ty, mem_ty, count, &mut addr).into_with_val(|| addr)
+        (self.allocate_pages)(ty, mem_ty, count, &mut addr).into_with_val(|| PageAllocation {
+            boot_services: self,
+            addr,
+            count,
+        })
     }
 
     /// Frees memory pages allocated by UEFI.
+    ///
+    /// This is only needed for pages which were released from their
+    /// `PageAllocation` using `PageAllocation::into_raw`.
     pub fn free_pages(&self, addr: u64, count: usize) -> Result {
         (self.free_pages)(addr, count).into()
     }
@@ -262,10 +301,7 @@ impl BootServices {
     pub fn memory_map<'buf>(
         &self,
         buffer: &'buf mut [u8],
-    ) -> Result<(
-        MemoryMapKey,
-        impl ExactSizeIterator<Item = &'buf MemoryDescriptor> + Clone,
-    )> {
+    ) -> Result<(MemoryMapKey, MemoryMapIter<'buf>)> {
         let mut map_size = buffer.len();
         MemoryDescriptor::assert_aligned(buffer);
         #[allow(clippy::cast_ptr_alignment)]
@@ -515,6 +551,86 @@ impl BootServices {
         })
     }
 
+    /// Installs a protocol interface on a handle, so that other code can
+    /// find it with `locate_handle` and open it with `open_protocol`.
+    ///
+    /// If `handle` is `None`, a new handle is created. The handle on which
+    /// the interface was installed is returned.
+    ///
+    /// # Safety
+    ///
+    /// `interface` must point to a valid instance of the protocol identified
+    /// by `protocol` (or be null for protocols without an interface), and
+    /// must stay valid until it is uninstalled with
+    /// `uninstall_protocol_interface`. In particular, the memory it points to
+    /// must not be freed, or be part of an image that is unloaded, while the
+    /// interface is installed.
+    ///
+    /// # Errors
+    /// * `uefi::Status::OUT_OF_RESOURCES`   The handle or interface could not be allocated.
+    /// * `uefi::Status::INVALID_PARAMETER`  The protocol is already installed on `handle`.
+    pub unsafe fn install_protocol_interface(
+        &self,
+        mut handle: Option<Handle>,
+        protocol: &Guid,
+        interface: *mut c_void,
+    ) -> Result<Handle> {
+        const NATIVE_INTERFACE: u32 = 0;
+        (self.install_protocol_interface)(&mut handle, protocol, NATIVE_INTERFACE, interface)
+            .into_with_val(|| handle.expect("install_protocol_interface returned a null handle"))
+    }
+
+    /// Removes a protocol interface installed with
+    /// `install_protocol_interface` from a handle.
+    ///
+    /// The handle is deleted if this was the last interface installed on it.
+    ///
+    /// # Safety
+    ///
+    /// `handle` must be a valid handle. If it was deleted because this was
+    /// its last interface, it must not be used anymore.
+    ///
+    /// # Errors
+    /// * `uefi::Status::NOT_FOUND`      The interface is not installed on `handle`.
+    /// * `uefi::Status::ACCESS_DENIED`  The interface is still used by a driver.
+    pub unsafe fn uninstall_protocol_interface(
+        &self,
+        handle: Handle,
+        protocol: &Guid,
+        interface: *mut c_void,
+    ) -> Result {
+        (self.uninstall_protocol_interface)(handle, protocol, interface).into()
+    }
+
+    /// Registers `event` to be signalled whenever a protocol interface is
+    /// installed for `protocol`.
+    ///
+    /// The returned `SearchType::ByRegisterNotify` can be passed to
+    /// `locate_handle` or `locate_handle_buffer` to find the handles on
+    /// which new interfaces were installed since the last search.
+    ///
+    /// This is the usual way to react to new devices, for example after
+    /// calling `connect_controller`: wait for `event` to be signalled, then
+    /// search for the new handles. Closing `event` with `close_event` cancels
+    /// the registration.
+    pub fn register_protocol_notify<'guid>(
+        &self,
+        protocol: &'guid Guid,
+        event: Event,
+    ) -> Result<(Event, SearchType<'guid>)> {
+        let mut key = MaybeUninit::<ProtocolSearchKey>::uninit();
+        // Safety: we clone `event` a couple times, but there will be only
+        // one left once we return.
+        (self.register_protocol_notify)(protocol, unsafe { event.unsafe_clone() }, key.as_mut_ptr())
+            // Safety: as long as this call is successful, `key` is valid.
+            .into_with_val(|| unsafe {
+                (
+                    event.unsafe_clone(),
+                    SearchType::ByRegisterNotify(key.assume_init()),
+                )
+            })
+    }
+
     /// Enumerates all handles installed on the system which match a certain query.
     ///
     /// You should first call this function with `None` for the output buffer,
@@ -535,11 +651,7 @@ impl BootServices {
             None => (0, NULL_BUFFER),
         };
 
-        // Obtain the needed data from the parameters.
-        let (ty, guid, key) = match search_ty {
-            SearchType::AllHandles => (0, ptr::null(), ptr::null_mut()),
-            SearchType::ByProtocol(guid) => (2, guid as *const _, ptr::null_mut()),
-        };
+        let (ty, guid, key) = search_ty.to_raw();
 
         let status = unsafe { (self.locate_handle)(ty, guid, key, &mut buffer_size, buffer) };
 
@@ -570,35 +682,134 @@ impl BootServices {
         }
     }
 
+    /// Connects drivers to the `controller` handle.
+    ///
+    /// If `driver_image` is `None`, the firmware tries all available drivers
+    /// in order of precedence; otherwise only the given driver is used.
+    ///
+    /// `remaining_device_path` is passed to the drivers' `Supported` and
+    /// `Start` functions. If it is `None`, drivers create handles for all
+    /// the children of the controller; otherwise only for the child device
+    /// at that path.
+    ///
+    /// If `recursive` is true, this function is called again for all the
+    /// children created by the connected drivers, connecting the whole tree
+    /// of devices below `controller`.
+    ///
+    /// # Errors
+    /// * `uefi::Status::NOT_FOUND`          No driver was connected to `controller`.
+    /// * `uefi::Status::SECURITY_VIOLATION` Starting drivers for the controller is not allowed.
+    pub fn connect_controller(
+        &self,
+        controller: Handle,
+        driver_image: Option<Handle>,
+        remaining_device_path: Option<&DevicePath>,
+        recursive: bool,
+    ) -> Result {
+        // The firmware expects a null-terminated list of driver images.
+        let driver_images = [driver_image, None];
+        let driver_images = match driver_image {
+            Some(_) => driver_images.as_ptr(),
+            None => ptr::null(),
+        };
+        let remaining_device_path = remaining_device_path
+            .map(|path| path as *const DevicePath)
+            .unwrap_or(ptr::null());
+
+        unsafe {
+            (self.connect_controller)(controller, driver_images, remaining_device_path, recursive)
+        }
+        .into()
+    }
+
+    /// Disconnects drivers from the `controller` handle.
+    ///
+    /// If `driver_image` is `None`, all the drivers managing `controller` are
+    /// disconnected. If `child` is `Some`, only that child controller is
+    /// destroyed; otherwise all the children of `controller` are.
+    ///
+    /// # Errors
+    /// * `uefi::Status::INVALID_PARAMETER` `driver_image` or `child` are not valid handles.
+    /// * `uefi::Status::DEVICE_ERROR`      A driver could not be disconnected.
+    pub fn disconnect_controller(
+        &self,
+        controller: Handle,
+        driver_image: Option<Handle>,
+        child: Option<Handle>,
+    ) -> Result {
+        unsafe { (self.disconnect_controller)(controller, driver_image, child) }.into()
+    }
+
     /// Load an EFI image from a buffer.
+    ///
+    /// If the image fails Secure Boot verification, a `SECURITY_VIOLATION`
+    /// error is returned and the image is unloaded again. Other errors, such
+    /// as `LOAD_ERROR` for a malformed image, are propagated as-is.
     pub fn load_image_from_buffer(
         &self,
         parent_image_handle: Handle,
         source_buffer: &[u8],
     ) -> Result<Handle> {
-        let boot_policy = 0;
-        let device_path = ptr::null();
-        let source_size = source_buffer.len();
-        let mut image_handle = MaybeUninit::uninit();
         unsafe {
-            (self.load_image)(
-                boot_policy,
+            self.load_image_impl(
                 parent_image_handle,
-                device_path,
+                ptr::null(),
                 source_buffer.as_ptr(),
-                source_size,
-                &mut image_handle,
+                source_buffer.len(),
             )
-            .into_with_val(|| image_handle.assume_init())
         }
     }
 
+    /// Load an EFI image from a device path, e.g. the path of a file on a
+    /// filesystem.
+    ///
+    /// If the image fails Secure Boot verification, a `SECURITY_VIOLATION`
+    /// error is returned and the image is unloaded again. Other errors, such
+    /// as `NOT_FOUND` if the file does not exist, are propagated as-is.
+    pub fn load_image_from_device_path(
+        &self,
+        parent_image_handle: Handle,
+        device_path: &DevicePath,
+    ) -> Result<Handle> {
+        unsafe { self.load_image_impl(parent_image_handle, device_path, ptr::null(), 0) }
+    }
+
+    unsafe fn load_image_impl(
+        &self,
+        parent_image_handle: Handle,
+        device_path: *const DevicePath,
+        source_buffer: *const u8,
+        source_size: usize,
+    ) -> Result<Handle> {
+        let boot_policy = 0;
+        let mut image_handle = MaybeUninit::uninit();
+        let status = (self.load_image)(
+            boot_policy,
+            parent_image_handle,
+            device_path,
+            source_buffer,
+            source_size,
+            &mut image_handle,
+        );
+
+        // On a Secure Boot violation the image is still loaded, but it may
+        // not be started. Unload it so that it does not leak.
+        if status == Status::SECURITY_VIOLATION {
+            let _ = self.unload_image(image_handle.assume_init());
+        }
+
+        status.into_with_val(|| image_handle.assume_init())
+    }
+
     /// Unload an EFI image.
     pub fn unload_image(&self, image_handle: Handle) -> Result {
         (self.unload_image)(image_handle).into()
     }
 
     /// Transfer control to a loaded image's entry point.
+    ///
+    /// Once the image exits, its exit status is returned. Error statuses are
+    /// turned into errors, and warnings into a `Completion` with a warning.
     pub fn start_image(&self, image_handle: Handle) -> Result {
         unsafe {
             // TODO: implement returning exit data to the caller.
@@ -652,6 +863,14 @@ impl BootServices {
         assert_eq!((self.stall)(time), Status::SUCCESS);
     }
 
+    /// Stalls the processor for the given duration.
+    ///
+    /// The duration is rounded down to whole microseconds, and saturates at
+    /// `usize::MAX` microseconds.
+    pub fn stall_duration(&self, duration: Duration) {
+        self.stall(duration_to_micros(duration))
+    }
+
     /// Set the watchdog timer.
     ///
     /// UEFI will start a 5-minute countdown after an UEFI image is loaded.
@@ -694,6 +913,17 @@ impl BootServices {
         unsafe { (self.set_watchdog_timer)(timeout, watchdog_code, data_len, data) }.into()
     }
 
+    /// Disable the watchdog timer.
+    ///
+    /// This prevents the firmware from resetting the system when the
+    /// watchdog timer expires, which long-running applications need to do.
+    /// See `set_watchdog_timer` for details.
+    pub fn disable_watchdog_timer(&self) -> Result {
+        // The watchdog code is unused when the timer is disabled, but it
+        // still must not be one of the reserved firmware codes.
+        self.set_watchdog_timer(0, 0x10000, None)
+    }
+
     /// Open a protocol interface for a handle.
     ///
     /// This function attempts to get the protocol implementation of a
@@ -740,6 +970,39 @@ impl BootServices {
         })
     }
 
+    /// Open a protocol interface for a handle in shared mode.
+    ///
+    /// This is `open_protocol` with [`OpenProtocolAttributes::GetProtocol`],
+    /// which does not disconnect the drivers that are using the protocol
+    /// interface. The same interface can be opened several times this way,
+    /// by the same or different agents. This is the right choice for most
+    /// applications.
+    pub fn open_protocol_shared<P: Protocol>(
+        &self,
+        params: OpenProtocolParams,
+    ) -> Result<ScopedProtocol<'_, P>> {
+        self.open_protocol(params, OpenProtocolAttributes::GetProtocol)
+    }
+
+    /// Open a protocol interface for a handle in exclusive mode.
+    ///
+    /// This is `open_protocol` with [`OpenProtocolAttributes::Exclusive`].
+    /// Opening a protocol exclusively stops the drivers that are using it,
+    /// which can have side effects beyond the calling application. For
+    /// example, opening the `Serial` protocol of the serial device used for
+    /// the console exclusively disconnects that device from stdout.
+    ///
+    /// # Errors
+    /// * `uefi::Status::ACCESS_DENIED`     The interface is already opened exclusively.
+    /// * `uefi::Status::ALREADY_STARTED`   The interface is already opened by the same agent.
+    /// * `uefi::Status::UNSUPPORTED`       The handle does not support the protocol.
+    pub fn open_protocol_exclusive<P: Protocol>(
+        &self,
+        params: OpenProtocolParams,
+    ) -> Result<ScopedProtocol<'_, P>> {
+        self.open_protocol(params, OpenProtocolAttributes::Exclusive)
+    }
+
     /// Test whether a handle supports a protocol.
     pub fn test_protocol<P: Protocol>(&self, params: OpenProtocolParams) -> Result<()> {
         const TEST_PROTOCOL: u32 = 0x04;
@@ -757,6 +1020,9 @@ impl BootServices {
 
     /// Get the list of protocol interface [`Guids`][Guid] that are installed
     /// on a [`Handle`].
+    ///
+    /// The returned buffer is allocated from the pool, and is freed when the
+    /// [`ProtocolsPerHandle`] is dropped.
     pub fn protocols_per_handle(&self, handle: Handle) -> Result<ProtocolsPerHandle> {
         let mut protocols = ptr::null_mut();
         let mut count = 0;
@@ -783,6 +1049,30 @@ impl BootServices {
         })
     }
 
+    /// Returns all handles installed on the system which match a certain
+    /// query, in a buffer allocated from the pool.
+    ///
+    /// Unlike `locate_handle`, the buffer is allocated by the firmware, so
+    /// this only needs to be called once. It is freed when the returned
+    /// [`HandleBuffer`] is dropped.
+    pub fn locate_handle_buffer(&self, search_ty: SearchType) -> Result<HandleBuffer<'_>> {
+        let mut count = 0;
+        let mut buffer = ptr::null_mut();
+        let (ty, guid, key) = search_ty.to_raw();
+
+        let mut status =
+            unsafe { (self.locate_handle_buffer)(ty, guid, key, &mut count, &mut buffer) };
+        if !status.is_error() && buffer.is_null() {
+            status = Status::OUT_OF_RESOURCES;
+        }
+
+        status.into_with_val(|| HandleBuffer {
+            boot_services: self,
+            buffer,
+            count,
+        })
+    }
+
     /// Returns a protocol implementation, if present on the system.
     ///
     /// The caveats of `BootServices::handle_protocol()` also apply here.
@@ -1051,6 +1341,9 @@ pub enum Tpl: usize => {
 /// RAII guard for task priority level changes
 ///
 /// Will automatically restore the former task priority level when dropped.
+/// The guard borrows the boot services, so it cannot outlive them: the task
+/// priority level is always restored before boot services are exited.
+#[must_use = "the task priority level is restored as soon as the guard is dropped"]
 pub struct TplGuard<'boot> {
     boot_services: &'boot BootServices,
     old_tpl: Tpl,
@@ -1151,7 +1444,83 @@ impl<'a, P: Protocol> Drop for ScopedProtocol<'a, P> {
         // passed to open_protocol. The public API prevents such errors,
         // and the error can't be propagated out of drop anyway, so just
         // assert success.
-        assert_eq!(status, Status::SUCCESS);
+        //
+        // The only exception is when the same interface was opened
+        // several times with the same parameters, which the firmware
+        // tracks as a single open: once one of the `ScopedProtocol`s has
+        // closed it, closing the others fails with `NOT_FOUND`.
+        assert!(
+            status == Status::SUCCESS || status == Status::NOT_FOUND,
+            "Failed to close protocol: {:?}",
+            status
+        );
+    }
+}
+
+/// Memory pages allocated by `BootServices::allocate_pages`.
+///
+/// The pages are freed when this value is dropped, unless they are released
+/// using `into_raw`.
+pub struct PageAllocation<'boot> {
+    // The pages have to be free'd with `free_pages`, so keep a reference to
+    // boot services for that purpose.
+    boot_services: &'boot BootServices,
+    /// An `u64` is used even on 32-bit platforms because some hardware
+    /// configurations like Intel PAE enable 64-bit physical addressing on a
+    /// 32-bit processor.
+    addr: u64,
+    count: usize,
+}
+
+impl<'boot> PageAllocation<'boot> {
+    /// Physical address of the first page.
+    pub fn address(&self) -> u64 {
+        self.addr
+    }
+
+    /// Number of allocated pages.
+    pub fn page_count(&self) -> usize {
+        self.count
+    }
+
+    /// Release ownership of the pages, without freeing them, and return
+    /// their physical address.
+    ///
+    /// The pages can later be freed using `BootServices::free_pages`.
+    pub fn into_raw(self) -> u64 {
+        let addr = self.addr;
+        mem::forget(self);
+        addr
+    }
+}
+
+impl<'boot> Deref for PageAllocation<'boot> {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        unsafe { slice::from_raw_parts(self.addr as *const u8, self.count * PAGE_SIZE) }
+    }
+}
+
+impl<'boot> DerefMut for PageAllocation<'boot> {
+    fn deref_mut(&mut self) -> &mut [u8] {
+        unsafe { slice::from_raw_parts_mut(self.addr as *mut u8, self.count * PAGE_SIZE) }
+    }
+}
+
+impl<'boot> Drop for PageAllocation<'boot> {
+    fn drop(&mut self) {
+        // Ignore the result, we can't do anything about an error here.
+        let _ = self.boot_services.free_pages(self.addr, self.count);
+    }
+}
+
+impl<'boot> Debug for PageAllocation<'boot> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("PageAllocation")
+            .field("addr", &(self.addr as *const u8))
+            .field("count", &self.count)
+            .finish()
     }
 }
 
@@ -1219,11 +1588,42 @@ impl MemoryType {
         assert!(value >= 0x80000000);
         MemoryType(value)
     }
+
+    /// Returns true if this memory type is in the range reserved for OEMs,
+    /// `0x70000000..=0x7fffffff`.
+    pub const fn is_oem_defined(self) -> bool {
+        self.0 >= 0x70000000 && self.0 <= 0x7fffffff
+    }
+
+    /// Returns true if this memory type is in the range reserved for OS
+    /// loaders, `0x80000000..=0xffffffff`, such as the types built with
+    /// `MemoryType::custom`.
+    pub const fn is_os_defined(self) -> bool {
+        self.0 >= 0x80000000
+    }
+
+    /// Returns true if memory of this type is free for the OS to use once
+    /// boot services have exited: `CONVENTIONAL`, `BOOT_SERVICES_CODE` and
+    /// `BOOT_SERVICES_DATA`.
+    ///
+    /// Memory allocated by the loader (`LOADER_CODE` and `LOADER_DATA`) is
+    /// not included, since it may hold data handed over to the OS.
+    pub const fn is_conventional_usable(self) -> bool {
+        matches!(
+            self,
+            MemoryType::CONVENTIONAL
+                | MemoryType::BOOT_SERVICES_CODE
+                | MemoryType::BOOT_SERVICES_DATA
+        )
+    }
 }
 
 /// Memory descriptor version number
 pub const MEMORY_DESCRIPTOR_VERSION: u32 = 1;
 
+/// Size in bytes of a UEFI memory page
+pub const PAGE_SIZE: usize = 4096;
+
 /// A structure describing a region of memory.
 #[derive(Debug, Copy, Clone)]
 #[repr(C)]
@@ -1255,6 +1655,40 @@ impl Default for MemoryDescriptor {
     }
 }
 
+impl MemoryDescriptor {
+    /// Size of this memory range in bytes.
+    pub const fn byte_len(&self) -> u64 {
+        self.page_count * PAGE_SIZE as u64
+    }
+
+    /// Physical address of the end of this memory range (exclusive).
+    pub const fn phys_end(&self) -> u64 {
+        self.phys_start + self.byte_len()
+    }
+
+    /// Virtual address of the end of this memory range (exclusive).
+    pub const fn virt_end(&self) -> u64 {
+        self.virt_start + self.byte_len()
+    }
+
+    /// Returns true if this memory range must be mapped by the OS when a
+    /// runtime service is called.
+    pub const fn is_runtime(&self) -> bool {
+        self.att.contains(MemoryAttribute::RUNTIME)
+    }
+
+    /// Returns true if this memory range supports write-back caching.
+    pub const fn is_write_back(&self) -> bool {
+        self.att.contains(MemoryAttribute::WRITE_BACK)
+    }
+
+    /// Returns true if this memory range supports being marked as
+    /// uncacheable.
+    pub const fn is_uncacheable(&self) -> bool {
+        self.att.contains(MemoryAttribute::UNCACHEABLE)
+    }
+}
+
 impl Align for MemoryDescriptor {
     fn alignment() -> usize {
         mem::align_of::<Self>()
@@ -1308,8 +1742,11 @@ pub struct MemoryMapSize {
 }
 
 /// An iterator of memory descriptors
+///
+/// The firmware may use a larger stride between descriptors than the size of
+/// `MemoryDescriptor`, which this iterator takes into account.
 #[derive(Debug, Clone)]
-struct MemoryMapIter<'buf> {
+pub struct MemoryMapIter<'buf> {
     buffer: &'buf [u8],
     entry_size: usize,
     index: usize,
@@ -1342,6 +1779,56 @@ impl<'buf> Iterator for MemoryMapIter<'buf> {
 
 impl ExactSizeIterator for MemoryMapIter<'_> {}
 
+impl<'buf> MemoryMapIter<'buf> {
+    /// Iterate over the descriptors of memory regions of type `ty`.
+    pub fn filter_by_type(
+        self,
+        ty: MemoryType,
+    ) -> impl Iterator<Item = &'buf MemoryDescriptor> + Clone {
+        self.filter(move |desc| desc.ty == ty)
+    }
+
+    /// Compute the total size in bytes of the memory regions of type `ty`.
+    ///
+    /// For example, the amount of free memory can be obtained with
+    /// `MemoryType::CONVENTIONAL`.
+    pub fn total_bytes_of_type(self, ty: MemoryType) -> u64 {
+        self.filter_by_type(ty)
+            .map(MemoryDescriptor::byte_len)
+            .sum()
+    }
+}
+
+/// Sort memory descriptors by physical address, and merge the adjacent
+/// descriptors that have the same type and attributes.
+///
+/// Two descriptors are merged if the first one ends exactly where the second
+/// one starts, both physically and virtually. Descriptors whose virtual
+/// address has not been set yet only need to be physically adjacent. This is
+/// useful to build a compact memory map to hand over to an OS.
+#[cfg(feature = "exts")]
+pub fn sorted_coalesced(iter: impl Iterator<Item = MemoryDescriptor>) -> Vec<MemoryDescriptor> {
+    let mut descriptors: Vec<MemoryDescriptor> = iter.collect();
+    descriptors.sort_unstable_by_key(|desc| desc.phys_start);
+
+    let mut coalesced: Vec<MemoryDescriptor> = Vec::with_capacity(descriptors.len());
+    for desc in descriptors {
+        match coalesced.last_mut() {
+            Some(last)
+                if last.ty == desc.ty
+                    && last.att == desc.att
+                    && last.phys_end() == desc.phys_start
+                    && (last.virt_end() == desc.virt_start
+                        || (last.virt_start == 0 && desc.virt_start == 0)) =>
+            {
+                last.page_count += desc.page_count;
+            }
+            _ => coalesced.push(desc),
+        }
+    }
+    coalesced
+}
+
 /// The type of handle search to perform.
 #[derive(Debug, Copy, Clone)]
 pub enum SearchType<'guid> {
@@ -1352,7 +1839,9 @@ pub enum SearchType<'guid> {
     /// If the protocol implements the `Protocol` interface,
     /// you can use the `from_proto` function to construct a new `SearchType`.
     ByProtocol(&'guid Guid),
-    // TODO: add ByRegisterNotify once the corresponding function is implemented.
+    /// Returns the next handle on which a new interface was installed for
+    /// the protocol passed to `BootServices::register_protocol_notify`.
+    ByRegisterNotify(ProtocolSearchKey),
 }
 
 impl<'guid> SearchType<'guid> {
@@ -1360,8 +1849,24 @@ impl<'guid> SearchType<'guid> {
     pub fn from_proto<P: Protocol>() -> Self {
         SearchType::ByProtocol(&P::GUID)
     }
+
+    /// Split into the search type, protocol and search key arguments of
+    /// `LocateHandle` and `LocateHandleBuffer`.
+    fn to_raw(self) -> (i32, *const Guid, *mut c_void) {
+        match self {
+            SearchType::AllHandles => (0, ptr::null(), ptr::null_mut()),
+            SearchType::ByRegisterNotify(key) => (1, ptr::null(), key.0.as_ptr()),
+            SearchType::ByProtocol(guid) => (2, guid as *const _, ptr::null_mut()),
+        }
+    }
 }
 
+/// Opaque key returned by `BootServices::register_protocol_notify`, used to
+/// search for newly installed protocol interfaces.
+#[derive(Debug, Copy, Clone)]
+#[repr(transparent)]
+pub struct ProtocolSearchKey(NonNull<c_void>);
+
 bitflags! {
     /// Flags describing the type of an UEFI event and its attributes.
     pub struct EventType: u32 {
@@ -1412,6 +1917,32 @@ pub enum TimerTrigger {
     Relative(u64),
 }
 
+impl TimerTrigger {
+    /// The event is to be signaled periodically, every `period`.
+    ///
+    /// The period is rounded down to 100ns units.
+    pub fn periodic(period: Duration) -> Self {
+        TimerTrigger::Periodic(duration_to_100ns(period))
+    }
+
+    /// The event is to be signaled once, after `delay`.
+    ///
+    /// The delay is rounded down to 100ns units.
+    pub fn relative(delay: Duration) -> Self {
+        TimerTrigger::Relative(duration_to_100ns(delay))
+    }
+}
+
+/// Convert a duration to microseconds, saturating on overflow.
+fn duration_to_micros(duration: Duration) -> usize {
+    usize::try_from(duration.as_micros()).unwrap_or(usize::MAX)
+}
+
+/// Convert a duration to 100ns units, saturating on overflow.
+fn duration_to_100ns(duration: Duration) -> u64 {
+    u64::try_from(duration.as_nanos() / 100).unwrap_or(u64::MAX)
+}
+
 /// Protocol interface [`Guids`][Guid] that are installed on a [`Handle`] as
 /// returned by [`BootServices::protocols_per_handle`].
 pub struct ProtocolsPerHandle<'a> {
@@ -1433,9 +1964,266 @@ impl<'a> Drop for ProtocolsPerHandle<'a> {
 impl<'a> ProtocolsPerHandle<'a> {
     /// Get the protocol interface [`Guids`][Guid] that are installed on the
     /// [`Handle`].
+    ///
+    /// The firmware returns an array of pointers to GUIDs which it owns, so
+    /// this slice holds references rather than copies of the GUIDs.
     pub fn protocols<'b>(&'b self) -> &'b [&'a Guid] {
         // convert raw pointer to slice here so that we can get
         // appropriate lifetime of the slice.
         unsafe { slice::from_raw_parts(self.protocols, self.count) }
     }
 }
+
+/// A buffer of handles returned by `BootServices::locate_handle_buffer`,
+/// which dereferences to a slice of [`Handle`]s.
+pub struct HandleBuffer<'a> {
+    // The buffer returned by `locate_handle_buffer` has to be free'd with
+    // `free_pool`, so keep a reference to boot services for that purpose.
+    boot_services: &'a BootServices,
+
+    buffer: *mut Handle,
+    count: usize,
+}
+
+impl<'a> Deref for HandleBuffer<'a> {
+    type Target = [Handle];
+
+    fn deref(&self) -> &[Handle] {
+        unsafe { slice::from_raw_parts(self.buffer, self.count) }
+    }
+}
+
+impl<'a> Drop for HandleBuffer<'a> {
+    fn drop(&mut self) {
+        // Ignore the result, we can't do anything about an error here.
+        let _ = self.boot_services.free_pool(self.buffer as *mut u8);
+    }
+}
+
+impl<'a> Debug for HandleBuffer<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_memory_map_iter() {
+        // Use a larger stride than the size of a descriptor, like some
+        // firmware does.
+        let entry_size = mem::size_of::<MemoryDescriptor>() + 8;
+        let descriptors = [
+            (MemoryType::CONVENTIONAL, 0x0, 10),
+            (MemoryType::LOADER_CODE, 0x10000, 2),
+            (MemoryType::CONVENTIONAL, 0x20000, 5),
+            (MemoryType::RESERVED, 0x30000, 1),
+        ];
+
+        let mut storage = [0u64; 32];
+        let buffer = unsafe {
+            slice::from_raw_parts_mut(storage.as_mut_ptr() as *mut u8, mem::size_of_val(&storage))
+        };
+        for (i, &(ty, phys_start, page_count)) in descriptors.iter().enumerate() {
+            let desc = MemoryDescriptor {
+                ty,
+                phys_start,
+                page_count,
+                ..Default::default()
+            };
+            unsafe {
+                (buffer.as_mut_ptr().add(i * entry_size) as *mut MemoryDescriptor).write(desc);
+            }
+        }
+
+        let iter = MemoryMapIter {
+            buffer,
+            entry_size,
+            index: 0,
+            len: descriptors.len(),
+        };
+
+        assert_eq!(iter.len(), 4);
+        let starts: [u64; 4] = [0x0, 0x10000, 0x20000, 0x30000];
+        assert!(iter.clone().map(|desc| desc.phys_start).eq(starts));
+
+        let conventional = iter.clone().filter_by_type(MemoryType::CONVENTIONAL);
+        assert!(conventional.map(|desc| desc.page_count).eq([10, 5]));
+
+        assert_eq!(
+            iter.clone().total_bytes_of_type(MemoryType::CONVENTIONAL),
+            15 * 4096
+        );
+        assert_eq!(iter.clone().total_bytes_of_type(MemoryType::RESERVED), 4096);
+        assert_eq!(iter.total_bytes_of_type(MemoryType::ACPI_RECLAIM), 0);
+    }
+
+    #[cfg(feature = "exts")]
+    #[test]
+    fn test_sorted_coalesced() {
+        let desc = |ty, phys_start, page_count, att| MemoryDescriptor {
+            ty,
+            phys_start,
+            page_count,
+            att,
+            ..Default::default()
+        };
+        let wb = MemoryAttribute::WRITE_BACK;
+        let uc = MemoryAttribute::UNCACHEABLE;
+
+        let descriptors = [
+            // Out of order, and adjacent to the first one.
+            desc(MemoryType::CONVENTIONAL, 0x3000, 2, wb),
+            desc(MemoryType::CONVENTIONAL, 0x0, 3, wb),
+            // Adjacent, but of a different type.
+            desc(MemoryType::LOADER_DATA, 0x5000, 1, wb),
+            // Adjacent, same type, but different attributes.
+            desc(MemoryType::LOADER_DATA, 0x6000, 1, uc),
+            // Same type and attributes, but not adjacent.
+            desc(MemoryType::LOADER_DATA, 0x8000, 1, uc),
+            desc(MemoryType::LOADER_DATA, 0x9000, 4, uc),
+        ];
+
+        let coalesced = sorted_coalesced(descriptors.iter().copied());
+        let ranges: Vec<_> = coalesced
+            .iter()
+            .map(|desc| (desc.ty, desc.phys_start, desc.page_count, desc.att))
+            .collect();
+        assert_eq!(
+            ranges,
+            [
+                (MemoryType::CONVENTIONAL, 0x0, 5, wb),
+                (MemoryType::LOADER_DATA, 0x5000, 1, wb),
+                (MemoryType::LOADER_DATA, 0x6000, 1, uc),
+                (MemoryType::LOADER_DATA, 0x8000, 5, uc),
+            ]
+        );
+
+        assert!(sorted_coalesced(core::iter::empty()).is_empty());
+
+        // Physically adjacent descriptors are only merged if they are also
+        // virtually adjacent.
+        let mapped = |phys_start, virt_start| MemoryDescriptor {
+            virt_start,
+            ..desc(MemoryType::RUNTIME_SERVICES_DATA, phys_start, 1, wb)
+        };
+        let descriptors = [
+            mapped(0x1000, 0xffff_0000_0000_1000),
+            mapped(0x2000, 0xffff_0000_0000_2000),
+            mapped(0x3000, 0xffff_0000_0001_0000),
+        ];
+        let coalesced = sorted_coalesced(descriptors.iter().copied());
+        let ranges: Vec<_> = coalesced
+            .iter()
+            .map(|desc| (desc.phys_start, desc.virt_start, desc.page_count))
+            .collect();
+        assert_eq!(
+            ranges,
+            [
+                (0x1000, 0xffff_0000_0000_1000, 2),
+                (0x3000, 0xffff_0000_0001_0000, 1),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_memory_descriptor_helpers() {
+        let desc = MemoryDescriptor {
+            ty: MemoryType::RUNTIME_SERVICES_DATA,
+            phys_start: 0x10_0000,
+            virt_start: 0xffff_8000_0010_0000,
+            page_count: 3,
+            att: MemoryAttribute::RUNTIME | MemoryAttribute::WRITE_BACK,
+            ..Default::default()
+        };
+        assert_eq!(desc.byte_len(), 3 * 4096);
+        assert_eq!(desc.phys_end(), 0x10_3000);
+        assert_eq!(desc.virt_end(), 0xffff_8000_0010_3000);
+        assert!(desc.is_runtime());
+        assert!(desc.is_write_back());
+        assert!(!desc.is_uncacheable());
+
+        let desc = MemoryDescriptor {
+            ty: MemoryType::MMIO,
+            phys_start: 0xfe00_0000,
+            page_count: 0,
+            att: MemoryAttribute::UNCACHEABLE,
+            ..Default::default()
+        };
+        assert_eq!(desc.byte_len(), 0);
+        assert_eq!(desc.phys_end(), desc.phys_start);
+        assert!(!desc.is_runtime());
+        assert!(!desc.is_write_back());
+        assert!(desc.is_uncacheable());
+    }
+
+    #[test]
+    fn test_memory_type_ranges() {
+        assert_eq!(MemoryType::custom(0x80000000), MemoryType(0x80000000));
+        assert!(MemoryType::custom(0x80000000).is_os_defined());
+        assert!(MemoryType::custom(0xffffffff).is_os_defined());
+        assert!(!MemoryType::custom(0x80000000).is_oem_defined());
+
+        assert!(MemoryType(0x70000000).is_oem_defined());
+        assert!(MemoryType(0x7fffffff).is_oem_defined());
+        assert!(!MemoryType(0x6fffffff).is_oem_defined());
+        assert!(!MemoryType(0x7fffffff).is_os_defined());
+
+        for ty in [MemoryType::CONVENTIONAL, MemoryType::PERSISTENT_MEMORY] {
+            assert!(!ty.is_oem_defined());
+            assert!(!ty.is_os_defined());
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_memory_type_custom_out_of_range() {
+        let _ = MemoryType::custom(0x7fffffff);
+    }
+
+    #[test]
+    fn test_memory_type_usable() {
+        assert!(MemoryType::CONVENTIONAL.is_conventional_usable());
+        assert!(MemoryType::BOOT_SERVICES_CODE.is_conventional_usable());
+        assert!(MemoryType::BOOT_SERVICES_DATA.is_conventional_usable());
+
+        for ty in [
+            MemoryType::RESERVED,
+            MemoryType::LOADER_CODE,
+            MemoryType::LOADER_DATA,
+            MemoryType::RUNTIME_SERVICES_CODE,
+            MemoryType::RUNTIME_SERVICES_DATA,
+            MemoryType::UNUSABLE,
+            MemoryType::ACPI_RECLAIM,
+            MemoryType::MMIO,
+            MemoryType::custom(0x80000000),
+        ] {
+            assert!(!ty.is_conventional_usable());
+        }
+    }
+
+    #[test]
+    fn test_duration_conversions() {
+        assert_eq!(duration_to_micros(Duration::from_secs(1)), 1_000_000);
+        assert_eq!(duration_to_micros(Duration::from_nanos(1_999)), 1);
+        assert_eq!(duration_to_micros(Duration::MAX), usize::MAX);
+
+        assert_eq!(duration_to_100ns(Duration::from_secs(1)), 10_000_000);
+        assert_eq!(duration_to_100ns(Duration::from_nanos(199)), 1);
+        assert_eq!(duration_to_100ns(Duration::MAX), u64::MAX);
+    }
+
+    #[test]
+    fn test_timer_trigger_duration() {
+        assert!(matches!(
+            TimerTrigger::relative(Duration::from_millis(1)),
+            TimerTrigger::Relative(10_000)
+        ));
+        assert!(matches!(
+            TimerTrigger::periodic(Duration::from_micros(5)),
+            TimerTrigger::Periodic(50)
+        ));
+    }
+}
@@ -0,0 +1,253 @@
+//! ACPI tables referenced from the configuration table.
+//!
+//! Both `ACPI_GUID` and `ACPI2_GUID` entries point to a Root System Description
+//! Pointer (RSDP), which in turn gives access to the rest of the ACPI tables.
+
+use super::checksum;
+use core::ffi::c_void;
+use core::{mem, slice};
+
+/// Signature found at the start of every RSDP.
+const RSDP_SIGNATURE: &[u8; 8] = b"RSD PTR ";
+
+/// ACPI 1.0 part of the Root System Description Pointer
+///
+/// This is all that is present if `revision()` is less than 2.
+#[derive(Clone, Copy, Debug)]
+#[repr(C, packed)]
+pub struct RsdpV1 {
+    signature: [u8; 8],
+    checksum: u8,
+    oem_id: [u8; 6],
+    revision: u8,
+    rsdt_address: u32,
+}
+
+impl RsdpV1 {
+    /// The signature of this structure, always "RSD PTR "
+    pub fn signature(&self) -> &[u8; 8] {
+        &self.signature
+    }
+
+    /// Checksum of the ACPI 1.0 part of this structure
+    pub fn checksum(&self) -> u8 {
+        self.checksum
+    }
+
+    /// OEM-supplied string identifying the OEM
+    pub fn oem_id(&self) -> &[u8; 6] {
+        &self.oem_id
+    }
+
+    /// Revision of this structure: 0 for ACPI 1.0, 2 for ACPI 2.0 and later
+    pub fn revision(&self) -> u8 {
+        self.revision
+    }
+
+    /// Physical address of the Root System Description Table
+    pub fn rsdt_address(&self) -> u32 {
+        self.rsdt_address
+    }
+}
+
+/// Root System Description Pointer, as defined by ACPI 2.0 and later
+#[derive(Debug)]
+#[repr(C, packed)]
+pub struct Rsdp {
+    v1: RsdpV1,
+    length: u32,
+    xsdt_address: u64,
+    extended_checksum: u8,
+    reserved: [u8; 3],
+}
+
+impl Rsdp {
+    /// Interpret the address of an `ACPI_GUID` or `ACPI2_GUID` config table
+    /// entry as an RSDP, validating its signature and checksums.
+    ///
+    /// Only the ACPI 1.0 part of the structure is read unless its revision
+    /// is 2 or greater.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that `addr` points to a readable RSDP, whose
+    /// size is given by its revision and length fields, and that this memory
+    /// stays valid for the lifetime `'a`.
+    pub unsafe fn from_config_table<'a>(
+        addr: *const c_void,
+    ) -> core::result::Result<RsdpRef<'a>, RsdpError> {
+        if addr.is_null() {
+            return Err(RsdpError::NullPointer);
+        }
+
+        let v1_bytes = slice::from_raw_parts(addr as *const u8, mem::size_of::<RsdpV1>());
+        if &v1_bytes[..RSDP_SIGNATURE.len()] != RSDP_SIGNATURE {
+            return Err(RsdpError::InvalidSignature);
+        }
+        if checksum(v1_bytes) != 0 {
+            return Err(RsdpError::InvalidChecksum);
+        }
+
+        let v1 = &*(addr as *const RsdpV1);
+        if v1.revision < 2 {
+            return Ok(RsdpRef::V1(v1));
+        }
+
+        // The length field directly follows the ACPI 1.0 part.
+        let length_ptr = (addr as *const u8).add(mem::size_of::<RsdpV1>()) as *const u32;
+        let length = length_ptr.read_unaligned() as usize;
+        if length < mem::size_of::<Rsdp>() {
+            return Err(RsdpError::InvalidLength);
+        }
+        let bytes = slice::from_raw_parts(addr as *const u8, length);
+        if checksum(bytes) != 0 {
+            return Err(RsdpError::InvalidChecksum);
+        }
+
+        Ok(RsdpRef::V2(&*(addr as *const Rsdp)))
+    }
+
+    /// The ACPI 1.0 part of this structure
+    pub fn v1(&self) -> &RsdpV1 {
+        &self.v1
+    }
+
+    /// Length of the whole structure, in bytes
+    pub fn length(&self) -> u32 {
+        self.length
+    }
+
+    /// Physical address of the Extended System Description Table
+    pub fn xsdt_address(&self) -> u64 {
+        self.xsdt_address
+    }
+
+    /// Checksum of the whole structure
+    pub fn extended_checksum(&self) -> u8 {
+        self.extended_checksum
+    }
+}
+
+/// A validated RSDP, whose layout depends on its revision
+#[derive(Clone, Copy, Debug)]
+pub enum RsdpRef<'a> {
+    /// An ACPI 1.0 RSDP, with a revision less than 2
+    V1(&'a RsdpV1),
+    /// An ACPI 2.0 or later RSDP
+    V2(&'a Rsdp),
+}
+
+impl<'a> RsdpRef<'a> {
+    /// The ACPI 1.0 part of the RSDP, which is always present
+    pub fn v1(&self) -> &'a RsdpV1 {
+        match *self {
+            RsdpRef::V1(v1) => v1,
+            RsdpRef::V2(rsdp) => rsdp.v1(),
+        }
+    }
+
+    /// Physical address of the Extended System Description Table
+    ///
+    /// This is only available if the revision is 2 or greater.
+    pub fn xsdt_address(&self) -> Option<u64> {
+        match *self {
+            RsdpRef::V1(_) => None,
+            RsdpRef::V2(rsdp) => Some(rsdp.xsdt_address()),
+        }
+    }
+}
+
+/// Errors that can occur when parsing an `Rsdp`
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RsdpError {
+    /// The config table address was null.
+    NullPointer,
+    /// The structure did not start with the "RSD PTR " signature.
+    InvalidSignature,
+    /// The bytes of the structure did not sum to zero.
+    InvalidChecksum,
+    /// The length of an ACPI 2.0 structure was too small.
+    InvalidLength,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Build a valid ACPI 2.0 RSDP, stored in a `u64` array for alignment.
+    fn build_rsdp() -> [u64; 5] {
+        let mut storage = [0u64; 5];
+        let bytes = unsafe { slice::from_raw_parts_mut(storage.as_mut_ptr() as *mut u8, 36) };
+        bytes[..8].copy_from_slice(RSDP_SIGNATURE);
+        bytes[9..15].copy_from_slice(b"UEFIRS");
+        bytes[15] = 2;
+        bytes[16..20].copy_from_slice(&0x1234_5678u32.to_le_bytes());
+        bytes[20..24].copy_from_slice(&36u32.to_le_bytes());
+        bytes[24..32].copy_from_slice(&0x1_2345_6789u64.to_le_bytes());
+        bytes[8] = 0u8.wrapping_sub(checksum(&bytes[..20]));
+        bytes[32] = 0u8.wrapping_sub(checksum(bytes));
+        storage
+    }
+
+    #[test]
+    fn test_rsdp() {
+        let storage = build_rsdp();
+        let rsdp = unsafe { Rsdp::from_config_table(storage.as_ptr().cast()) }.unwrap();
+
+        assert_eq!(rsdp.v1().signature(), b"RSD PTR ");
+        assert_eq!(rsdp.v1().oem_id(), b"UEFIRS");
+        assert_eq!(rsdp.v1().revision(), 2);
+        assert_eq!(rsdp.v1().rsdt_address(), 0x1234_5678);
+        assert_eq!(rsdp.xsdt_address(), Some(0x1_2345_6789));
+    }
+
+    #[test]
+    fn test_rsdp_v1() {
+        // An ACPI 1.0 RSDP is only 20 bytes long, and nothing after it may be
+        // read.
+        let mut storage = [0u32; 5];
+        let bytes = unsafe { slice::from_raw_parts_mut(storage.as_mut_ptr() as *mut u8, 20) };
+        bytes[..8].copy_from_slice(RSDP_SIGNATURE);
+        bytes[9..15].copy_from_slice(b"UEFIRS");
+        bytes[16..20].copy_from_slice(&0x1234_5678u32.to_le_bytes());
+        bytes[8] = 0u8.wrapping_sub(checksum(bytes));
+
+        let rsdp = unsafe { Rsdp::from_config_table(storage.as_ptr().cast()) }.unwrap();
+        assert!(matches!(rsdp, RsdpRef::V1(_)));
+        assert_eq!(rsdp.v1().revision(), 0);
+        assert_eq!(rsdp.v1().rsdt_address(), 0x1234_5678);
+        assert_eq!(rsdp.xsdt_address(), None);
+    }
+
+    #[test]
+    fn test_rsdp_errors() {
+        let parse =
+            |storage: &[u64; 5]| unsafe { Rsdp::from_config_table(storage.as_ptr().cast()) };
+
+        let mut storage = build_rsdp();
+        let bytes = unsafe { slice::from_raw_parts_mut(storage.as_mut_ptr() as *mut u8, 36) };
+        bytes[0] = b'X';
+        assert_eq!(parse(&storage).unwrap_err(), RsdpError::InvalidSignature);
+
+        let mut storage = build_rsdp();
+        let bytes = unsafe { slice::from_raw_parts_mut(storage.as_mut_ptr() as *mut u8, 36) };
+        bytes[9] ^= 1;
+        assert_eq!(parse(&storage).unwrap_err(), RsdpError::InvalidChecksum);
+
+        // Corrupting the ACPI 2.0 part only breaks the extended checksum.
+        let mut storage = build_rsdp();
+        let bytes = unsafe { slice::from_raw_parts_mut(storage.as_mut_ptr() as *mut u8, 36) };
+        bytes[24] ^= 1;
+        assert_eq!(parse(&storage).unwrap_err(), RsdpError::InvalidChecksum);
+
+        let mut storage = build_rsdp();
+        let bytes = unsafe { slice::from_raw_parts_mut(storage.as_mut_ptr() as *mut u8, 36) };
+        bytes[20..24].copy_from_slice(&20u32.to_le_bytes());
+        assert_eq!(parse(&storage).unwrap_err(), RsdpError::InvalidLength);
+
+        assert_eq!(
+            unsafe { Rsdp::from_config_table(core::ptr::null()) }.unwrap_err(),
+            RsdpError::NullPointer
+        );
+    }
+}
@@ -0,0 +1,482 @@
+//! SMBIOS tables referenced from the configuration table.
+//!
+//! The `SMBIOS_GUID` and `SMBIOS3_GUID` entries point to an entry point
+//! structure, which gives the location of the table of SMBIOS structures.
+//! That table can be walked with `SmbiosStructures`.
+
+use super::checksum;
+use core::ffi::c_void;
+use core::slice;
+
+/// Anchor string found at the start of an SMBIOS 2.1 entry point.
+const ANCHOR: &[u8; 4] = b"_SM_";
+
+/// Intermediate anchor string of an SMBIOS 2.1 entry point.
+const INTERMEDIATE_ANCHOR: &[u8; 5] = b"_DMI_";
+
+/// Anchor string found at the start of an SMBIOS 3.0 entry point.
+const ANCHOR3: &[u8; 5] = b"_SM3_";
+
+/// 32-bit SMBIOS entry point, pointed to by the `SMBIOS_GUID` entry.
+#[derive(Debug)]
+#[repr(C, packed)]
+pub struct SmbiosEntryPoint {
+    anchor: [u8; 4],
+    checksum: u8,
+    length: u8,
+    major_version: u8,
+    minor_version: u8,
+    max_structure_size: u16,
+    entry_point_revision: u8,
+    formatted_area: [u8; 5],
+    intermediate_anchor: [u8; 5],
+    intermediate_checksum: u8,
+    structure_table_length: u16,
+    structure_table_address: u32,
+    number_of_structures: u16,
+    bcd_revision: u8,
+}
+
+impl SmbiosEntryPoint {
+    /// Offset of the intermediate anchor, which starts the part of the
+    /// structure covered by the intermediate checksum.
+    const INTERMEDIATE_OFFSET: usize = 16;
+
+    /// Interpret the address of an `SMBIOS_GUID` config table entry as an
+    /// SMBIOS entry point, validating its anchors and checksums.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that `addr` points to readable memory that is
+    /// large enough to hold the entry point, and that this memory stays
+    /// valid for the lifetime `'a`.
+    pub unsafe fn from_config_table<'a>(
+        addr: *const c_void,
+    ) -> core::result::Result<&'a SmbiosEntryPoint, SmbiosError> {
+        if addr.is_null() {
+            return Err(SmbiosError::NullPointer);
+        }
+
+        let entry_point = &*(addr as *const SmbiosEntryPoint);
+        if &entry_point.anchor != ANCHOR || &entry_point.intermediate_anchor != INTERMEDIATE_ANCHOR
+        {
+            return Err(SmbiosError::InvalidAnchor);
+        }
+        let length = entry_point.length as usize;
+        if length < core::mem::size_of::<SmbiosEntryPoint>() {
+            return Err(SmbiosError::InvalidLength);
+        }
+        let bytes = slice::from_raw_parts(addr as *const u8, length);
+        if checksum(bytes) != 0 || checksum(&bytes[Self::INTERMEDIATE_OFFSET..]) != 0 {
+            return Err(SmbiosError::InvalidChecksum);
+        }
+
+        Ok(entry_point)
+    }
+
+    /// Major version of the SMBIOS specification implemented
+    pub fn major_version(&self) -> u8 {
+        self.major_version
+    }
+
+    /// Minor version of the SMBIOS specification implemented
+    pub fn minor_version(&self) -> u8 {
+        self.minor_version
+    }
+
+    /// Size of the largest SMBIOS structure, including its string set
+    pub fn max_structure_size(&self) -> u16 {
+        self.max_structure_size
+    }
+
+    /// Physical address of the table of SMBIOS structures
+    pub fn structure_table_address(&self) -> u64 {
+        u64::from(self.structure_table_address)
+    }
+
+    /// Total length in bytes of the table of SMBIOS structures
+    pub fn structure_table_length(&self) -> u32 {
+        u32::from(self.structure_table_length)
+    }
+
+    /// Number of structures in the table of SMBIOS structures
+    pub fn number_of_structures(&self) -> u16 {
+        self.number_of_structures
+    }
+
+    /// Iterate over the structures of the table this entry point refers to.
+    ///
+    /// # Safety
+    ///
+    /// The structure table address must be identity-mapped and valid for
+    /// the lifetime `'a`, which is the case while boot services are active.
+    pub unsafe fn structures<'a>(&self) -> SmbiosStructures<'a> {
+        SmbiosStructures::new(slice::from_raw_parts(
+            self.structure_table_address() as *const u8,
+            self.structure_table_length() as usize,
+        ))
+    }
+}
+
+/// 64-bit SMBIOS 3.0 entry point, pointed to by the `SMBIOS3_GUID` entry.
+#[derive(Debug)]
+#[repr(C, packed)]
+pub struct Smbios3EntryPoint {
+    anchor: [u8; 5],
+    checksum: u8,
+    length: u8,
+    major_version: u8,
+    minor_version: u8,
+    docrev: u8,
+    entry_point_revision: u8,
+    reserved: u8,
+    structure_table_max_size: u32,
+    structure_table_address: u64,
+}
+
+impl Smbios3EntryPoint {
+    /// Interpret the address of an `SMBIOS3_GUID` config table entry as an
+    /// SMBIOS 3.0 entry point, validating its anchor and checksum.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that `addr` points to readable memory that is
+    /// large enough to hold the entry point, and that this memory stays
+    /// valid for the lifetime `'a`.
+    pub unsafe fn from_config_table<'a>(
+        addr: *const c_void,
+    ) -> core::result::Result<&'a Smbios3EntryPoint, SmbiosError> {
+        if addr.is_null() {
+            return Err(SmbiosError::NullPointer);
+        }
+
+        let entry_point = &*(addr as *const Smbios3EntryPoint);
+        if &entry_point.anchor != ANCHOR3 {
+            return Err(SmbiosError::InvalidAnchor);
+        }
+        let length = entry_point.length as usize;
+        if length < core::mem::size_of::<Smbios3EntryPoint>() {
+            return Err(SmbiosError::InvalidLength);
+        }
+        if checksum(slice::from_raw_parts(addr as *const u8, length)) != 0 {
+            return Err(SmbiosError::InvalidChecksum);
+        }
+
+        Ok(entry_point)
+    }
+
+    /// Major version of the SMBIOS specification implemented
+    pub fn major_version(&self) -> u8 {
+        self.major_version
+    }
+
+    /// Minor version of the SMBIOS specification implemented
+    pub fn minor_version(&self) -> u8 {
+        self.minor_version
+    }
+
+    /// Revision of the SMBIOS specification document implemented
+    pub fn docrev(&self) -> u8 {
+        self.docrev
+    }
+
+    /// Physical address of the table of SMBIOS structures
+    pub fn structure_table_address(&self) -> u64 {
+        self.structure_table_address
+    }
+
+    /// Maximum length in bytes of the table of SMBIOS structures
+    ///
+    /// The actual table may be shorter, it ends with a structure of type 127.
+    pub fn structure_table_max_size(&self) -> u32 {
+        self.structure_table_max_size
+    }
+
+    /// Iterate over the structures of the table this entry point refers to.
+    ///
+    /// # Safety
+    ///
+    /// The structure table address must be identity-mapped and valid for
+    /// the lifetime `'a`, which is the case while boot services are active.
+    pub unsafe fn structures<'a>(&self) -> SmbiosStructures<'a> {
+        SmbiosStructures::new(slice::from_raw_parts(
+            self.structure_table_address() as *const u8,
+            self.structure_table_max_size() as usize,
+        ))
+    }
+}
+
+/// Type of the structure which marks the end of the structure table.
+pub const END_OF_TABLE: u8 = 127;
+
+/// Iterator over a table of SMBIOS structures.
+///
+/// Iteration stops at the end-of-table structure (type 127), at the end of
+/// the table, or at the first malformed structure.
+#[derive(Clone, Copy, Debug)]
+pub struct SmbiosStructures<'a> {
+    table: &'a [u8],
+}
+
+impl<'a> SmbiosStructures<'a> {
+    /// Iterate over the structures contained in `table`.
+    pub fn new(table: &'a [u8]) -> Self {
+        SmbiosStructures { table }
+    }
+}
+
+impl<'a> Iterator for SmbiosStructures<'a> {
+    type Item = SmbiosStructure<'a>;
+
+    fn next(&mut self) -> Option<SmbiosStructure<'a>> {
+        let table = self.table;
+        // Stop iterating for good, whatever the reason.
+        self.table = &[];
+
+        // The formatted area starts with a 4-byte header, which contains its
+        // length.
+        if table.len() < 4 || table[0] == END_OF_TABLE {
+            return None;
+        }
+        let length = table[1] as usize;
+        if length < 4 || length > table.len() {
+            return None;
+        }
+        let formatted = &table[..length];
+
+        // The string set is terminated by two null bytes, even if it is
+        // empty.
+        let strings_len = table[length..].windows(2).position(|w| w == [0, 0])?;
+        let strings = &table[length..length + strings_len];
+
+        self.table = &table[length + strings_len + 2..];
+        Some(SmbiosStructure { formatted, strings })
+    }
+}
+
+/// View of an SMBIOS structure, made of a formatted area and a string set.
+#[derive(Clone, Copy, Debug)]
+pub struct SmbiosStructure<'a> {
+    formatted: &'a [u8],
+    strings: &'a [u8],
+}
+
+impl<'a> SmbiosStructure<'a> {
+    /// Type of the structure, e.g. 1 for system information
+    pub fn struct_type(&self) -> u8 {
+        self.formatted[0]
+    }
+
+    /// Handle of the structure, which other structures may refer to
+    pub fn handle(&self) -> u16 {
+        u16::from_le_bytes([self.formatted[2], self.formatted[3]])
+    }
+
+    /// The formatted area of the structure, including its 4-byte header
+    pub fn formatted(&self) -> &'a [u8] {
+        self.formatted
+    }
+
+    /// Iterate over the strings of the structure's string set, without their
+    /// null terminators.
+    pub fn strings(&self) -> SmbiosStrings<'a> {
+        SmbiosStrings {
+            strings: self.strings,
+        }
+    }
+
+    /// Get a string referenced by a field of the formatted area.
+    ///
+    /// String indices start at 1, the index 0 is used by fields which don't
+    /// refer to any string.
+    pub fn string(&self, index: u8) -> Option<&'a [u8]> {
+        let index = (index as usize).checked_sub(1)?;
+        self.strings().nth(index)
+    }
+}
+
+/// Iterator over the strings of an SMBIOS structure, see
+/// `SmbiosStructure::strings`.
+#[derive(Clone, Copy, Debug)]
+pub struct SmbiosStrings<'a> {
+    strings: &'a [u8],
+}
+
+impl<'a> Iterator for SmbiosStrings<'a> {
+    type Item = &'a [u8];
+
+    fn next(&mut self) -> Option<&'a [u8]> {
+        if self.strings.is_empty() {
+            return None;
+        }
+        let (string, rest) = match self.strings.iter().position(|&b| b == 0) {
+            Some(nul) => (&self.strings[..nul], &self.strings[nul + 1..]),
+            None => (self.strings, &[][..]),
+        };
+        self.strings = rest;
+        Some(string)
+    }
+}
+
+/// Errors that can occur when parsing an SMBIOS entry point
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SmbiosError {
+    /// The config table address was null.
+    NullPointer,
+    /// The structure did not start with the expected anchor string.
+    InvalidAnchor,
+    /// The bytes of the structure did not sum to zero.
+    InvalidChecksum,
+    /// The length of the structure was too small.
+    InvalidLength,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Build a valid SMBIOS 3.0 entry point, stored in a `u64` array for
+    /// alignment.
+    fn build_entry_point3() -> [u64; 3] {
+        let mut storage = [0u64; 3];
+        let bytes = unsafe { slice::from_raw_parts_mut(storage.as_mut_ptr() as *mut u8, 24) };
+        bytes[..5].copy_from_slice(ANCHOR3);
+        bytes[6] = 24;
+        bytes[7] = 3;
+        bytes[8] = 2;
+        bytes[10] = 1;
+        bytes[12..16].copy_from_slice(&0x1000u32.to_le_bytes());
+        bytes[16..24].copy_from_slice(&0x1_2345_6000u64.to_le_bytes());
+        bytes[5] = 0u8.wrapping_sub(checksum(bytes));
+        storage
+    }
+
+    /// Build a valid SMBIOS 2.1 entry point, stored in a `u64` array for
+    /// alignment.
+    fn build_entry_point() -> [u64; 4] {
+        let mut storage = [0u64; 4];
+        let bytes = unsafe { slice::from_raw_parts_mut(storage.as_mut_ptr() as *mut u8, 31) };
+        bytes[..4].copy_from_slice(ANCHOR);
+        bytes[5] = 31;
+        bytes[6] = 2;
+        bytes[7] = 8;
+        bytes[8..10].copy_from_slice(&0x100u16.to_le_bytes());
+        bytes[16..21].copy_from_slice(INTERMEDIATE_ANCHOR);
+        bytes[22..24].copy_from_slice(&0x200u16.to_le_bytes());
+        bytes[24..28].copy_from_slice(&0xe_0000u32.to_le_bytes());
+        bytes[28..30].copy_from_slice(&9u16.to_le_bytes());
+        bytes[30] = 0x28;
+        bytes[21] = 0u8.wrapping_sub(checksum(&bytes[16..]));
+        bytes[4] = 0u8.wrapping_sub(checksum(bytes));
+        storage
+    }
+
+    #[test]
+    fn test_entry_point3() {
+        let storage = build_entry_point3();
+        let entry_point =
+            unsafe { Smbios3EntryPoint::from_config_table(storage.as_ptr().cast()) }.unwrap();
+
+        assert_eq!(entry_point.major_version(), 3);
+        assert_eq!(entry_point.minor_version(), 2);
+        assert_eq!(entry_point.structure_table_max_size(), 0x1000);
+        assert_eq!(entry_point.structure_table_address(), 0x1_2345_6000);
+    }
+
+    #[test]
+    fn test_entry_point3_errors() {
+        let parse = |storage: &[u64; 3]| unsafe {
+            Smbios3EntryPoint::from_config_table(storage.as_ptr().cast())
+        };
+
+        let mut storage = build_entry_point3();
+        let bytes = unsafe { slice::from_raw_parts_mut(storage.as_mut_ptr() as *mut u8, 24) };
+        bytes[3] = b'2';
+        assert_eq!(parse(&storage).unwrap_err(), SmbiosError::InvalidAnchor);
+
+        let mut storage = build_entry_point3();
+        let bytes = unsafe { slice::from_raw_parts_mut(storage.as_mut_ptr() as *mut u8, 24) };
+        bytes[16] ^= 1;
+        assert_eq!(parse(&storage).unwrap_err(), SmbiosError::InvalidChecksum);
+
+        let mut storage = build_entry_point3();
+        let bytes = unsafe { slice::from_raw_parts_mut(storage.as_mut_ptr() as *mut u8, 24) };
+        bytes[6] = 16;
+        assert_eq!(parse(&storage).unwrap_err(), SmbiosError::InvalidLength);
+
+        assert_eq!(
+            unsafe { Smbios3EntryPoint::from_config_table(core::ptr::null()) }.unwrap_err(),
+            SmbiosError::NullPointer
+        );
+    }
+
+    #[test]
+    fn test_structures() {
+        #[rustfmt::skip]
+        let table = [
+            // BIOS information, with two strings.
+            0, 6, 0x00, 0x01, 1, 2,
+            b'V', b'e', b'n', b'd', b'o', b'r', 0, b'1', b'.', b'0', 0, 0,
+            // System information, without strings.
+            1, 4, 0x01, 0x01,
+            0, 0,
+            // End of table.
+            127, 4, 0x02, 0x01,
+            0, 0,
+            // Garbage after the end of the table.
+            1, 4, 0x03, 0x01,
+            0, 0,
+        ];
+
+        let mut structures = SmbiosStructures::new(&table);
+
+        let bios = structures.next().unwrap();
+        assert_eq!(bios.struct_type(), 0);
+        assert_eq!(bios.handle(), 0x100);
+        assert_eq!(bios.formatted(), &[0, 6, 0x00, 0x01, 1, 2]);
+        assert_eq!(bios.string(1), Some(&b"Vendor"[..]));
+        assert_eq!(bios.string(2), Some(&b"1.0"[..]));
+        assert_eq!(bios.string(0), None);
+        assert_eq!(bios.string(3), None);
+        assert_eq!(bios.strings().count(), 2);
+
+        let system = structures.next().unwrap();
+        assert_eq!(system.struct_type(), 1);
+        assert_eq!(system.handle(), 0x101);
+        assert_eq!(system.strings().count(), 0);
+        assert_eq!(system.string(1), None);
+
+        assert!(structures.next().is_none());
+        assert!(structures.next().is_none());
+
+        // Truncated tables end the iteration.
+        assert_eq!(SmbiosStructures::new(&table[..10]).count(), 0);
+        assert_eq!(SmbiosStructures::new(&table[..3]).count(), 0);
+        assert_eq!(SmbiosStructures::new(&table[18..]).count(), 1);
+    }
+
+    #[test]
+    fn test_entry_point() {
+        let storage = build_entry_point();
+        let entry_point =
+            unsafe { SmbiosEntryPoint::from_config_table(storage.as_ptr().cast()) }.unwrap();
+
+        assert_eq!(entry_point.major_version(), 2);
+        assert_eq!(entry_point.minor_version(), 8);
+        assert_eq!(entry_point.max_structure_size(), 0x100);
+        assert_eq!(entry_point.structure_table_length(), 0x200);
+        assert_eq!(entry_point.structure_table_address(), 0xe_0000);
+        assert_eq!(entry_point.number_of_structures(), 9);
+
+        // The intermediate anchor and checksum are validated too.
+        let mut storage = build_entry_point();
+        let bytes = unsafe { slice::from_raw_parts_mut(storage.as_mut_ptr() as *mut u8, 31) };
+        bytes[16] = b'X';
+        let parse = unsafe { SmbiosEntryPoint::from_config_table(storage.as_ptr().cast()) };
+        assert_eq!(parse.unwrap_err(), SmbiosError::InvalidAnchor);
+        bytes[16] = b'_';
+        bytes[24] ^= 1;
+        let parse = unsafe { SmbiosEntryPoint::from_config_table(storage.as_ptr().cast()) };
+        assert_eq!(parse.unwrap_err(), SmbiosError::InvalidChecksum);
+    }
+}
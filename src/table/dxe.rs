@@ -0,0 +1,111 @@
+//! Driver eXecution Environment services.
+//!
+//! These services are not part of the UEFI specification, but of the Platform
+//! Initialization (PI) specification. Firmware based on EDK2 makes them
+//! available through the `DXE_SERVICES_GUID` config table entry.
+
+use super::Header;
+use crate::{Handle, PhysicalAddress, Result, Status};
+use core::fmt::{Debug, Formatter};
+use core::mem::MaybeUninit;
+
+/// Contains pointers to the DXE services.
+///
+/// This table can be obtained from `SystemTable::dxe_services`.
+#[repr(C)]
+pub struct DxeServices {
+    pub(super) header: Header,
+
+    // Global Coherency Domain services
+    // Skip add/allocate/free/remove_memory_space.
+    _pad: [usize; 4],
+    get_memory_space_descriptor: unsafe extern "efiapi" fn(
+        base_address: PhysicalAddress,
+        descriptor: *mut MemorySpaceDescriptor,
+    ) -> Status,
+    // Skip set_memory_space_attributes, get_memory_space_map and the
+    // I/O space functions.
+    _pad2: [usize; 8],
+
+    // Dispatcher services
+    dispatch: extern "efiapi" fn() -> Status,
+    // Skip schedule, trust, process_firmware_volume and
+    // set_memory_space_capabilities.
+    _pad3: [usize; 4],
+}
+
+impl DxeServices {
+    /// Retrieve the descriptor of the memory region containing `base_address`
+    /// from the Global Coherency Domain memory space map.
+    pub fn get_memory_space_descriptor(
+        &self,
+        base_address: PhysicalAddress,
+    ) -> Result<MemorySpaceDescriptor> {
+        let mut descriptor = MaybeUninit::<MemorySpaceDescriptor>::uninit();
+        unsafe { (self.get_memory_space_descriptor)(base_address, descriptor.as_mut_ptr()) }
+            .into_with_val(|| unsafe { descriptor.assume_init() })
+    }
+
+    /// Load and start all drivers that have been discovered in firmware
+    /// volumes but have not been dispatched yet.
+    ///
+    /// Returns `NOT_FOUND` if there were no drivers to dispatch.
+    pub fn dispatch(&self) -> Result {
+        (self.dispatch)().into()
+    }
+}
+
+impl super::Table for DxeServices {
+    const SIGNATURE: u64 = 0x5652_4553_5f45_5844;
+}
+
+impl Debug for DxeServices {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("DxeServices")
+            .field("header", &self.header)
+            .field(
+                "get_memory_space_descriptor",
+                &(self.get_memory_space_descriptor as *const usize),
+            )
+            .field("dispatch", &(self.dispatch as *const usize))
+            .finish()
+    }
+}
+
+/// Description of a memory region in the Global Coherency Domain memory
+/// space map.
+#[derive(Debug, Copy, Clone)]
+#[repr(C)]
+pub struct MemorySpaceDescriptor {
+    /// Physical address of the first byte of the region.
+    pub base_address: PhysicalAddress,
+    /// Size of the region in bytes.
+    pub length: u64,
+    /// Bit mask of the attributes supported by the region.
+    pub capabilities: u64,
+    /// Bit mask of the attributes currently set on the region.
+    pub attributes: u64,
+    /// Type of the region.
+    pub memory_type: GcdMemoryType,
+    /// Image that allocated the region, if it was allocated.
+    pub image_handle: Option<Handle>,
+    /// Device that the region was allocated for, if any.
+    pub device_handle: Option<Handle>,
+}
+
+newtype_enum! {
+/// Type of a memory region in the Global Coherency Domain memory space map.
+pub enum GcdMemoryType: u32 => {
+    /// The region is not present in the platform.
+    NON_EXISTENT      = 0,
+    /// The region is present but not usable as system memory.
+    RESERVED          = 1,
+    /// The region is usable as system memory.
+    SYSTEM_MEMORY     = 2,
+    /// The region is memory-mapped I/O.
+    MEMORY_MAPPED_IO  = 3,
+    /// The region is persistent memory.
+    PERSISTENT        = 4,
+    /// The region is higher reliability system memory.
+    MORE_RELIABLE     = 5,
+}}
@@ -20,3 +20,6 @@ pub mod boot;
 pub mod runtime;
 
 pub mod cfg;
+
+#[cfg(feature = "dxe")]
+pub mod dxe;
@@ -6,13 +6,43 @@ use core::fmt;
 ///
 /// The minor revision number is incremented on minor changes,
 /// it is stored as a two-digit binary-coded decimal.
+///
+/// Revisions are ordered, so they can be compared against the constants
+/// defined here, e.g. `rev >= Revision::EFI_2_30`.
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd)]
 pub struct Revision(u32);
 
 impl Revision {
+    /// EFI 1.02
+    pub const EFI_1_02: Self = Self::new(1, 2);
+    /// EFI 1.10
+    pub const EFI_1_10: Self = Self::new(1, 10);
+    /// UEFI 2.0
+    pub const EFI_2_00: Self = Self::new(2, 0);
+    /// UEFI 2.1
+    pub const EFI_2_10: Self = Self::new(2, 10);
+    /// UEFI 2.2
+    pub const EFI_2_20: Self = Self::new(2, 20);
+    /// UEFI 2.3
+    pub const EFI_2_30: Self = Self::new(2, 30);
+    /// UEFI 2.3.1
+    pub const EFI_2_31: Self = Self::new(2, 31);
+    /// UEFI 2.4
+    pub const EFI_2_40: Self = Self::new(2, 40);
+    /// UEFI 2.5
+    pub const EFI_2_50: Self = Self::new(2, 50);
+    /// UEFI 2.6
+    pub const EFI_2_60: Self = Self::new(2, 60);
+    /// UEFI 2.7
+    pub const EFI_2_70: Self = Self::new(2, 70);
+    /// UEFI 2.8
+    pub const EFI_2_80: Self = Self::new(2, 80);
+    /// UEFI 2.9
+    pub const EFI_2_90: Self = Self::new(2, 90);
+
     /// Creates a new revision.
-    pub fn new(major: u16, minor: u16) -> Self {
-        let (major, minor) = (u32::from(major), u32::from(minor));
+    pub const fn new(major: u16, minor: u16) -> Self {
+        let (major, minor) = (major as u32, minor as u32);
         let value = (major << 16) | minor;
         Revision(value)
     }
@@ -35,3 +65,51 @@ impl fmt::Debug for Revision {
         write!(f, "{}.{}.{}", major, minor / 10, minor % 10)
     }
 }
+
+impl fmt::Display for Revision {
+    /// Formats the revision the way the specifications name their versions,
+    /// e.g. `1.10` for EFI, or `2.3` and `2.3.1` for UEFI.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (major, minor) = (self.major(), self.minor());
+        // EFI 1.x versions always have a two-digit minor revision.
+        if major == 1 {
+            return write!(f, "{}.{:02}", major, minor);
+        }
+        write!(f, "{}.{}", major, minor / 10)?;
+        if minor % 10 != 0 {
+            write!(f, ".{}", minor % 10)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_revision() {
+        let rev = Revision::new(2, 31);
+        assert_eq!(rev.major(), 2);
+        assert_eq!(rev.minor(), 31);
+        assert_eq!(rev, Revision::EFI_2_31);
+
+        assert!(Revision::EFI_1_10 < Revision::EFI_2_00);
+        assert!(Revision::EFI_2_30 < Revision::EFI_2_31);
+        assert!(Revision::EFI_2_31 < Revision::EFI_2_40);
+        assert!(Revision::new(2, 70) >= Revision::EFI_2_30);
+    }
+
+    #[test]
+    fn test_revision_display() {
+        extern crate alloc;
+        use alloc::string::ToString;
+
+        assert_eq!(Revision::EFI_1_02.to_string(), "1.02");
+        assert_eq!(Revision::EFI_1_10.to_string(), "1.10");
+        assert_eq!(Revision::EFI_2_00.to_string(), "2.0");
+        assert_eq!(Revision::EFI_2_30.to_string(), "2.3");
+        assert_eq!(Revision::EFI_2_31.to_string(), "2.3.1");
+        assert_eq!(Revision::EFI_2_70.to_string(), "2.7");
+    }
+}
@@ -1,6 +1,6 @@
 //! UEFI services available at runtime, even after the OS boots.
 
-use super::Header;
+use super::{Header, Revision};
 #[cfg(feature = "exts")]
 use crate::data_types::FromSliceWithNulError;
 use crate::result::Error;
@@ -10,9 +10,7 @@ use crate::{CStr16, Char16, Guid, Result, Status};
 use alloc_api::{vec, vec::Vec};
 use bitflags::bitflags;
 use core::fmt::{Debug, Formatter};
-#[cfg(feature = "exts")]
-use core::mem;
-use core::mem::MaybeUninit;
+use core::mem::{self, MaybeUninit};
 use core::{fmt, ptr};
 /// Contains pointers to all of the runtime services.
 ///
@@ -60,6 +58,18 @@ pub struct RuntimeServices {
         data_size: usize,
         data: *const u8,
     ) -> !,
+
+    // UEFI 2.0 Capsule Services.
+    _update_capsule: usize,
+    _query_capsule_capabilities: usize,
+
+    // Miscellaneous UEFI 2.0 Service.
+    query_variable_info: unsafe extern "efiapi" fn(
+        attributes: VariableAttributes,
+        maximum_variable_storage_size: *mut u64,
+        remaining_variable_storage_size: *mut u64,
+        maximum_variable_size: *mut u64,
+    ) -> Status,
 }
 
 impl RuntimeServices {
@@ -114,7 +124,8 @@ impl RuntimeServices {
 
     /// Get the contents and attributes of a variable. The size of `buf` must
     /// be at least as big as the variable's size, although it can be
-    /// larger. If it is too small, `BUFFER_TOO_SMALL` is returned.
+    /// larger. If it is too small, `BUFFER_TOO_SMALL` is returned, along with
+    /// the required buffer size.
     ///
     /// On success, a tuple containing the variable's value (a slice of `buf`)
     /// and the variable's attributes is returned.
@@ -123,7 +134,7 @@ impl RuntimeServices {
         name: &CStr16,
         vendor: &VariableVendor,
         buf: &'a mut [u8],
-    ) -> Result<(&'a [u8], VariableAttributes)> {
+    ) -> Result<(&'a [u8], VariableAttributes), Option<usize>> {
         let mut attributes = VariableAttributes::empty();
         let mut data_size = buf.len();
         unsafe {
@@ -134,7 +145,16 @@ impl RuntimeServices {
                 &mut data_size,
                 buf.as_mut_ptr(),
             )
-            .into_with_val(move || (&buf[..data_size], attributes))
+            .into_with(
+                move || (&buf[..data_size], attributes),
+                |s| {
+                    if s == Status::BUFFER_TOO_SMALL {
+                        Some(data_size)
+                    } else {
+                        None
+                    }
+                },
+            )
         }
     }
 
@@ -221,15 +241,59 @@ impl RuntimeServices {
         }
     }
 
+    /// Get information about the storage available for variables with the
+    /// given `attributes`.
+    ///
+    /// This service was introduced in UEFI 2.0, `UNSUPPORTED` is returned on
+    /// older firmware.
+    pub fn query_variable_info(
+        &self,
+        attributes: VariableAttributes,
+    ) -> Result<VariableStorageInfo> {
+        if self.header.revision < Revision::EFI_2_00 {
+            return Err(Status::UNSUPPORTED.into());
+        }
+
+        let mut info = VariableStorageInfo {
+            maximum_variable_storage_size: 0,
+            remaining_variable_storage_size: 0,
+            maximum_variable_size: 0,
+        };
+        unsafe {
+            (self.query_variable_info)(
+                attributes,
+                &mut info.maximum_variable_storage_size,
+                &mut info.remaining_variable_storage_size,
+                &mut info.maximum_variable_size,
+            )
+        }
+        .into_with_val(|| info)
+    }
+
     /// Resets the computer.
+    ///
+    /// If provided, `data` must start with a null-terminated UCS-2 string
+    /// describing the reason for the reset, optionally followed by binary
+    /// data. For `ResetType::PlatformSpecific`, the string must be followed
+    /// by the GUID of the platform-specific reset type to perform.
+    ///
+    /// This function never returns: the firmware either resets the system or,
+    /// for `ResetType::Shutdown` on platforms that don't support it, performs
+    /// a cold reset instead.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rt` is `ResetType::PlatformSpecific` and `data` doesn't
+    /// contain a null-terminated string followed by a GUID.
     pub fn reset(&self, rt: ResetType, status: Status, data: Option<&[u8]>) -> ! {
+        if rt == ResetType::PlatformSpecific {
+            assert!(
+                matches!(data, Some(data) if is_valid_platform_specific_reset_data(data)),
+                "platform-specific resets require a string followed by a GUID"
+            );
+        }
+
         let (size, data) = match data {
-            // FIXME: The UEFI spec states that the data must start with a NUL-
-            //        terminated string, which we should check... but it does not
-            //        specify if that string should be Latin-1 or UCS-2!
-            //
-            //        PlatformSpecific resets should also insert a GUID after the
-            //        NUL-terminated string.
             Some(data) => (data.len(), data.as_ptr()),
             None => (0, ptr::null()),
         };
@@ -238,6 +302,15 @@ impl RuntimeServices {
     }
 }
 
+/// Check that `data` starts with a null-terminated UCS-2 string which is
+/// followed by a GUID, as required for platform-specific resets.
+fn is_valid_platform_specific_reset_data(data: &[u8]) -> bool {
+    match data.chunks_exact(2).position(|c| c == [0, 0]) {
+        Some(nul) => data.len() >= (nul + 1) * 2 + mem::size_of::<Guid>(),
+        None => false,
+    }
+}
+
 impl super::Table for RuntimeServices {
     const SIGNATURE: u64 = 0x5652_4553_544e_5552;
 }
@@ -253,6 +326,10 @@ impl Debug for RuntimeServices {
                 &(self.set_virtual_address_map as *const u64),
             )
             .field("reset", &(self.reset as *const u64))
+            .field(
+                "query_variable_info",
+                &(self.query_variable_info as *const u64),
+            )
             .finish()
     }
 }
@@ -286,7 +363,7 @@ bitflags! {
 
 impl Time {
     /// Unspecified Timezone/local time.
-    const UNSPECIFIED_TIMEZONE: i16 = 0x07ff;
+    pub const UNSPECIFIED_TIMEZONE: i16 = 0x07ff;
 
     /// Build an UEFI time struct
     #[allow(clippy::too_many_arguments)]
@@ -324,6 +401,26 @@ impl Time {
         }
     }
 
+    /// Create an invalid `Time` with all fields set to zero.
+    ///
+    /// This can be used with `FileInfo` to indicate that a time field should
+    /// not be updated when calling `File::set_info`.
+    pub const fn invalid() -> Self {
+        Self {
+            year: 0,
+            month: 0,
+            day: 0,
+            hour: 0,
+            minute: 0,
+            second: 0,
+            _pad1: 0,
+            nanosecond: 0,
+            time_zone: 0,
+            daylight: Daylight::empty(),
+            _pad2: 0,
+        }
+    }
+
     /// Query the year
     pub fn year(&self) -> u16 {
         self.year
@@ -372,6 +469,121 @@ impl Time {
     pub fn daylight(&self) -> Daylight {
         self.daylight
     }
+
+    /// Checks that all fields are within the ranges allowed by the UEFI
+    /// specification, including the day being within the month.
+    ///
+    /// Firmware may return garbage in time fields, so this should be
+    /// checked before relying on a `Time` obtained from it.
+    pub fn is_valid(&self) -> bool {
+        (1900..=9999).contains(&self.year)
+            && (1..=12).contains(&self.month)
+            && self.day >= 1
+            && self.day <= days_in_month(i64::from(self.year), self.month)
+            && self.hour <= 23
+            && self.minute <= 59
+            && self.second <= 59
+            && self.nanosecond <= 999_999_999
+            && ((-1440..=1440).contains(&self.time_zone)
+                || self.time_zone == Self::UNSPECIFIED_TIMEZONE)
+    }
+
+    /// Convert to the number of seconds since 1970-01-01 00:00:00 UTC.
+    ///
+    /// The `time_zone` offset is subtracted from the stored time to get UTC,
+    /// unless it is `UNSPECIFIED_TIMEZONE`, in which case the time is treated
+    /// as if it were UTC. Sub-second precision is discarded.
+    ///
+    /// Returns `None` if the stored time is not valid, e.g. for the zeroed
+    /// time returned by `Time::invalid`. See `is_valid`.
+    pub fn to_unix_timestamp(&self) -> Option<i64> {
+        if !self.is_valid() {
+            return None;
+        }
+
+        let days = days_from_civil(i64::from(self.year), self.month, self.day);
+        let mut secs = days * 86_400
+            + i64::from(self.hour) * 3600
+            + i64::from(self.minute) * 60
+            + i64::from(self.second);
+        if self.time_zone != Self::UNSPECIFIED_TIMEZONE {
+            secs -= i64::from(self.time_zone) * 60;
+        }
+        Some(secs)
+    }
+
+    /// Build a UTC time from the number of seconds since
+    /// 1970-01-01 00:00:00 UTC, plus some nanoseconds.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `nanos` is not smaller than one billion, or if the resulting
+    /// year is outside of the 1900 - 9999 range supported by UEFI.
+    pub fn from_unix_timestamp(secs: i64, nanos: u32) -> Time {
+        let days = secs.div_euclid(86_400);
+        let secs_of_day = secs.rem_euclid(86_400);
+        let (year, month, day) = civil_from_days(days);
+        assert!((1900..=9999).contains(&year));
+
+        Time::new(
+            year as u16,
+            month,
+            day,
+            (secs_of_day / 3600) as u8,
+            (secs_of_day / 60 % 60) as u8,
+            (secs_of_day % 60) as u8,
+            nanos,
+            0,
+            Daylight::empty(),
+        )
+    }
+}
+
+/// Whether `year` is a leap year in the Gregorian calendar.
+fn is_leap_year(year: i64) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+/// Number of days in the given month (1 - 12) of `year`.
+fn days_in_month(year: i64, month: u8) -> u8 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Number of days between 1970-01-01 and the given date of the proleptic
+/// Gregorian calendar.
+fn days_from_civil(year: i64, month: u8, day: u8) -> i64 {
+    // Count years from March, so that the leap day is the last day of a year.
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year.rem_euclid(400);
+    let month = i64::from(month);
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + i64::from(day) - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Inverse of `days_from_civil`, returns the `(year, month, day)` of a date.
+fn civil_from_days(days: i64) -> (i64, u8, u8) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * shifted_month + 2) / 5 + 1) as u8;
+    let month = if shifted_month < 10 {
+        shifted_month + 3
+    } else {
+        shifted_month - 9
+    } as u8;
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
 }
 
 impl fmt::Debug for Time {
@@ -401,23 +613,12 @@ impl fmt::Display for Time {
         )?;
 
         if self.time_zone == Self::UNSPECIFIED_TIMEZONE {
-            write!(f, " (local)")?;
+            write!(f, " (local)")
         } else {
-            let offset_in_hours = self.time_zone as f32 / 60.0;
-            let integer_part = offset_in_hours as i16;
-            // We can't use "offset_in_hours.fract()" because it is part of `std`.
-            let fraction_part = offset_in_hours - (integer_part as f32);
-            // most time zones
-            if fraction_part == 0.0 {
-                write!(f, "UTC+{}", offset_in_hours)?;
-            }
-            // time zones with 30min offset (and perhaps other special time zones)
-            else {
-                write!(f, "UTC+{:.1}", offset_in_hours)?;
-            }
+            let sign = if self.time_zone < 0 { '-' } else { '+' };
+            let offset = self.time_zone.unsigned_abs();
+            write!(f, " UTC{}{:02}:{:02}", sign, offset / 60, offset % 60)
         }
-
-        Ok(())
     }
 }
 
@@ -554,6 +755,22 @@ impl fmt::Display for VariableKey {
     }
 }
 
+/// Information about the storage available for variables, as returned by
+/// `RuntimeServices::query_variable_info`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct VariableStorageInfo {
+    /// Maximum size of the storage space available for variables with the
+    /// requested attributes.
+    pub maximum_variable_storage_size: u64,
+
+    /// Remaining size of the storage space available for variables with the
+    /// requested attributes.
+    pub remaining_variable_storage_size: u64,
+
+    /// Maximum size of an individual variable with the requested attributes.
+    pub maximum_variable_size: u64,
+}
+
 /// The type of system reset.
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 #[repr(u32)]
@@ -568,10 +785,182 @@ pub enum ResetType {
     Shutdown,
     /// A platform-specific reset type.
     ///
-    /// The additional data must be a pointer to
-    /// a null-terminated string followed by an UUID.
+    /// The reset data passed to `RuntimeServices::reset` must be a
+    /// null-terminated UCS-2 string followed by the GUID describing the
+    /// type of reset to perform.
     PlatformSpecific,
     // SAFETY: This enum is never exposed to the user, but only fed as input to
     //         the firmware. Therefore, unexpected values can never come from
     //         the firmware, and modeling this as a Rust enum seems safe.
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unix_timestamp() {
+        let time = Time::new(1970, 1, 1, 0, 0, 0, 0, 0, Daylight::IN_DAYLIGHT);
+        assert_eq!(time.to_unix_timestamp(), Some(0));
+        let time = Time::new(1971, 1, 1, 0, 0, 0, 0, 0, Daylight::IN_DAYLIGHT);
+        assert_eq!(time.to_unix_timestamp(), Some(31_536_000));
+        let time = Time::new(1972, 1, 1, 0, 0, 0, 0, 0, Daylight::IN_DAYLIGHT);
+        assert_eq!(time.to_unix_timestamp(), Some(63_072_000));
+
+        // 1972 is a leap year.
+        let time = Time::new(1972, 2, 29, 0, 0, 0, 0, 0, Daylight::empty());
+        assert_eq!(time.to_unix_timestamp(), Some(68_169_600));
+        let time = Time::new(1973, 1, 1, 0, 0, 0, 0, 0, Daylight::empty());
+        assert_eq!(time.to_unix_timestamp(), Some(94_694_400));
+
+        // Before the epoch.
+        let time = Time::new(1969, 12, 31, 23, 59, 59, 0, 0, Daylight::empty());
+        assert_eq!(time.to_unix_timestamp(), Some(-1));
+
+        // Time zones are honored, unless unspecified.
+        let time = Time::new(1970, 1, 1, 1, 0, 0, 0, 60, Daylight::empty());
+        assert_eq!(time.to_unix_timestamp(), Some(0));
+        let time = Time::new(
+            1970,
+            1,
+            1,
+            1,
+            0,
+            0,
+            0,
+            Time::UNSPECIFIED_TIMEZONE,
+            Daylight::empty(),
+        );
+        assert_eq!(time.to_unix_timestamp(), Some(3600));
+
+        // Invalid dates.
+        assert_eq!(Time::invalid().to_unix_timestamp(), None);
+        let time = Time::new(1971, 2, 29, 0, 0, 0, 0, 0, Daylight::empty());
+        assert_eq!(time.to_unix_timestamp(), None);
+    }
+
+    #[test]
+    fn test_is_valid() {
+        let time = |year, month, day| Time::new(year, month, day, 0, 0, 0, 0, 0, Daylight::empty());
+        assert!(time(1900, 1, 1).is_valid());
+        assert!(time(9999, 12, 31).is_valid());
+        assert!(time(1970, 1, 31).is_valid());
+        assert!(!time(1970, 4, 31).is_valid());
+        assert!(time(1970, 4, 30).is_valid());
+
+        // February 29 only exists in leap years.
+        assert!(time(1972, 2, 29).is_valid());
+        assert!(time(2000, 2, 29).is_valid());
+        assert!(!time(1971, 2, 29).is_valid());
+        assert!(!time(1900, 2, 29).is_valid());
+        assert!(time(1971, 2, 28).is_valid());
+
+        let max = Time::new(1970, 1, 1, 23, 59, 59, 999_999_999, 1440, Daylight::empty());
+        assert!(max.is_valid());
+        let min_tz = Time::new(1970, 1, 1, 0, 0, 0, 0, -1440, Daylight::empty());
+        assert!(min_tz.is_valid());
+
+        // Out-of-range fields can only come from the firmware, so patch them
+        // in after construction.
+        assert!(!Time::invalid().is_valid());
+        let mut bad = max;
+        bad.month = 13;
+        assert!(!bad.is_valid());
+        let mut bad = max;
+        bad.hour = 24;
+        assert!(!bad.is_valid());
+        let mut bad = max;
+        bad.minute = 60;
+        assert!(!bad.is_valid());
+        let mut bad = max;
+        bad.second = 60;
+        assert!(!bad.is_valid());
+        let mut bad = max;
+        bad.nanosecond = 1_000_000_000;
+        assert!(!bad.is_valid());
+        let mut bad = max;
+        bad.time_zone = 1441;
+        assert!(!bad.is_valid());
+        let mut bad = max;
+        bad.time_zone = -1441;
+        assert!(!bad.is_valid());
+        let mut bad = max;
+        bad.time_zone = Time::UNSPECIFIED_TIMEZONE;
+        assert!(bad.is_valid());
+    }
+
+    #[test]
+    fn test_platform_specific_reset_data() {
+        let guid = VariableVendor::GLOBAL_VARIABLE.0.to_bytes();
+        let mut data = [0u8; 6 + 16 + 1];
+        data[0] = b'a';
+        data[2] = b'b';
+        data[6..22].copy_from_slice(&guid);
+        assert!(is_valid_platform_specific_reset_data(&data[..22]));
+        // Extra data after the GUID is allowed.
+        assert!(is_valid_platform_specific_reset_data(&data));
+        // Missing or truncated GUID.
+        assert!(!is_valid_platform_specific_reset_data(&data[..6]));
+        assert!(!is_valid_platform_specific_reset_data(&data[..21]));
+        // Missing null terminator.
+        assert!(!is_valid_platform_specific_reset_data(&data[..4]));
+        assert!(!is_valid_platform_specific_reset_data(&[]));
+    }
+
+    #[test]
+    fn test_display() {
+        extern crate alloc;
+        use alloc::string::ToString;
+
+        let time = Time::new(1970, 1, 1, 0, 0, 0, 0, 0, Daylight::IN_DAYLIGHT);
+        assert_eq!(time.to_string(), "1970-01-01 00:00:00.000000000 UTC+00:00");
+        let time = Time::new(1971, 2, 3, 4, 5, 6, 7, -330, Daylight::empty());
+        assert_eq!(time.to_string(), "1971-02-03 04:05:06.000000007 UTC-05:30");
+        let time = Time::new(1972, 12, 31, 23, 59, 59, 0, 60, Daylight::empty());
+        assert_eq!(time.to_string(), "1972-12-31 23:59:59.000000000 UTC+01:00");
+        let time = Time::new(
+            1972,
+            1,
+            1,
+            0,
+            0,
+            0,
+            0,
+            Time::UNSPECIFIED_TIMEZONE,
+            Daylight::empty(),
+        );
+        assert_eq!(time.to_string(), "1972-01-01 00:00:00.000000000 (local)");
+    }
+
+    #[test]
+    fn test_from_unix_timestamp() {
+        assert_eq!(
+            Time::from_unix_timestamp(0, 0),
+            Time::new(1970, 1, 1, 0, 0, 0, 0, 0, Daylight::empty())
+        );
+        assert_eq!(
+            Time::from_unix_timestamp(31_536_000, 0),
+            Time::new(1971, 1, 1, 0, 0, 0, 0, 0, Daylight::empty())
+        );
+        assert_eq!(
+            Time::from_unix_timestamp(63_072_000, 0),
+            Time::new(1972, 1, 1, 0, 0, 0, 0, 0, Daylight::empty())
+        );
+        assert_eq!(
+            Time::from_unix_timestamp(-1, 5),
+            Time::new(1969, 12, 31, 23, 59, 59, 5, 0, Daylight::empty())
+        );
+
+        for &secs in &[
+            -2_208_988_800,
+            -1,
+            0,
+            68_169_600,
+            951_782_400,
+            4_102_444_799,
+        ] {
+            let time = Time::from_unix_timestamp(secs, 0);
+            assert_eq!(time.to_unix_timestamp(), Some(secs));
+        }
+    }
+}
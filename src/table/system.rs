@@ -5,12 +5,19 @@ use core::ptr::NonNull;
 use core::{ptr, slice};
 
 use crate::proto::console::text;
-use crate::{CStr16, Char16, Handle, Result, ResultExt, Status};
+use crate::{CStr16, Char16, Guid, Handle, Result, ResultExt, Status};
 
-use super::boot::{BootServices, MemoryDescriptor};
+use super::boot::{BootServices, MemoryDescriptor, MemoryMapIter};
+#[cfg(feature = "dxe")]
+use super::dxe::DxeServices;
 use super::runtime::RuntimeServices;
+#[cfg(feature = "dxe")]
+use super::Table;
 use super::{cfg, Header, Revision};
 
+/// Maximum number of attempts made by `SystemTable::exit_boot_services`
+const EXIT_BOOT_SERVICES_ATTEMPTS: usize = 5;
+
 /// Marker trait used to provide different views of the UEFI System Table
 pub trait SystemTableView {}
 
@@ -74,6 +81,18 @@ impl<View: SystemTableView> SystemTable<View> {
         unsafe { slice::from_raw_parts(self.table.cfg_table, self.table.nr_cfg) }
     }
 
+    /// Returns the address of the config table entry identified by `guid`,
+    /// such as `cfg::ACPI2_GUID`, or `None` if there is no such entry.
+    pub fn find_config_table(&self, guid: Guid) -> Option<*const c_void> {
+        self.entries().find_guid(guid)
+    }
+
+    /// Returns an iterator over the GUIDs and addresses of the config table
+    /// entries.
+    pub fn entries(&self) -> cfg::ConfigTableEntries<'_> {
+        cfg::ConfigTableEntries::new(self.config_table())
+    }
+
     /// Creates a new `SystemTable<View>` from a raw address. The address might
     /// come from the Multiboot2 information structure or something similar.
     ///
@@ -129,6 +148,21 @@ impl SystemTable<Boot> {
         unsafe { &*self.table.boot }
     }
 
+    /// Access the DXE services, if the firmware provides them
+    ///
+    /// Returns `None` if there is no `DXE_SERVICES_GUID` config table entry,
+    /// or if it does not point to a valid DXE services table.
+    #[cfg(feature = "dxe")]
+    pub fn dxe_services(&self) -> Option<&DxeServices> {
+        let ptr = self.find_config_table(cfg::DXE_SERVICES_GUID)?;
+        let dxe_services = unsafe { ptr.cast::<DxeServices>().as_ref()? };
+        if dxe_services.header.signature == <DxeServices as Table>::SIGNATURE {
+            Some(dxe_services)
+        } else {
+            None
+        }
+    }
+
     /// Exit the UEFI boot services
     ///
     /// After this function completes, UEFI hands over control of the hardware
@@ -163,6 +197,14 @@ impl SystemTable<Boot> {
     /// for the memory map right before exiting boot services, and to allocate a
     /// bit more storage than requested by memory_map_size.
     ///
+    /// Exiting boot services fails with `Status::INVALID_PARAMETER` if the
+    /// memory map changed since it was retrieved, which some firmware does
+    /// during the first attempt. In that case, the memory map is fetched again
+    /// and the exit is retried, up to 5 attempts in total. If the last attempt
+    /// still fails, `Status::INVALID_PARAMETER` is returned; boot services may
+    /// then be partially shut down, and only the memory map functions can be
+    /// used safely.
+    ///
     /// If `exit_boot_services` succeeds, it will return a runtime view of the
     /// system table which more accurately reflects the state of the UEFI
     /// firmware following exit from boot services, along with a high-level
@@ -175,14 +217,11 @@ impl SystemTable<Boot> {
         self,
         image: Handle,
         mmap_buf: &mut [u8],
-    ) -> Result<(
-        SystemTable<Runtime>,
-        impl ExactSizeIterator<Item = &MemoryDescriptor> + Clone,
-    )> {
+    ) -> Result<(SystemTable<Runtime>, MemoryMapIter<'_>)> {
         unsafe {
             let boot_services = self.boot_services();
 
-            loop {
+            for attempt in 1..=EXIT_BOOT_SERVICES_ATTEMPTS {
                 // Fetch a memory map, propagate errors and split the completion
                 // FIXME: This sad pointer hack works around a current
                 //        limitation of the NLL analysis (see Rust bug 51526).
@@ -194,7 +233,9 @@ impl SystemTable<Boot> {
                 let result = boot_services.exit_boot_services(image, mmap_key);
 
                 // Did we fail because the memory map was updated concurrently?
-                if result.status() == Status::INVALID_PARAMETER {
+                if result.status() == Status::INVALID_PARAMETER
+                    && attempt < EXIT_BOOT_SERVICES_ATTEMPTS
+                {
                     // If so, fetch another memory map and try again
                     continue;
                 } else {
@@ -208,6 +249,7 @@ impl SystemTable<Boot> {
                     });
                 }
             }
+            unreachable!()
         }
     }
 
@@ -4,13 +4,14 @@ extern crate proc_macro;
 
 use proc_macro::TokenStream;
 
-use proc_macro2::{TokenStream as TokenStream2, TokenTree};
-use quote::{quote, ToTokens, TokenStreamExt};
+use proc_macro2::{Span, TokenStream as TokenStream2, TokenTree};
+use quote::{quote, quote_spanned, ToTokens, TokenStreamExt};
 use syn::{
     parse::{Parse, ParseStream},
     parse_macro_input,
     spanned::Spanned,
-    DeriveInput, Error, Generics, Ident, ItemFn, ItemType, LitStr, Visibility,
+    DeriveInput, Error, Expr, ExprArray, ExprLit, FnArg, Generics, Ident, ItemFn, ItemType, Lit,
+    LitStr, MetaNameValue, ReturnType, Type, Visibility,
 };
 
 /// Parses a type definition, extracts its identifier and generic parameters
@@ -48,24 +49,60 @@ macro_rules! err {
 
 /// `unsafe_guid` attribute macro, implements the `Identify` trait for any type
 /// (mostly works like a custom derive, but also supports type aliases)
+///
+/// The GUID can either be given in canonical textual format, or as an array
+/// of 16 bytes in the mixed-endian layout used by UEFI (as found in EDK2's
+/// `EFI_GUID` byte dumps).
 #[proc_macro_attribute]
 pub fn unsafe_guid(args: TokenStream, input: TokenStream) -> TokenStream {
     // Parse the arguments and input using Syn
-    let (time_low, time_mid, time_high_and_version, clock_seq_and_variant, node) =
-        match parse_guid(parse_macro_input!(args as LitStr)) {
-            Ok(data) => data,
-            Err(tokens) => return tokens.into(),
-        };
+    let guid = match parse_guid_arg(args.into()) {
+        Ok(data) => data,
+        Err(tokens) => return tokens.into(),
+    };
 
     let mut result: TokenStream2 = input.clone().into();
 
     let type_definition = parse_macro_input!(input as TypeDefinition);
 
     // At this point, we know everything we need to implement Identify
-    let ident = &type_definition.ident;
-    let (impl_generics, ty_generics, where_clause) = type_definition.generics.split_for_impl();
+    result.append_all(identify_impl(
+        &type_definition.ident,
+        &type_definition.generics,
+        guid,
+    ));
+    result.into()
+}
+
+/// `protocol` attribute macro, implements the `Protocol` and `Identify` traits
+/// for a protocol struct in one go
+///
+/// This is equivalent to combining `#[unsafe_guid(...)]` and
+/// `#[derive(Protocol)]`, and accepts the GUID in the same forms as
+/// `unsafe_guid`.
+#[proc_macro_attribute]
+pub fn protocol(args: TokenStream, input: TokenStream) -> TokenStream {
+    let guid = match parse_guid_arg(args.into()) {
+        Ok(data) => data,
+        Err(tokens) => return tokens.into(),
+    };
 
-    result.append_all(quote! {
+    let mut result: TokenStream2 = input.clone().into();
+
+    let item = parse_macro_input!(input as DeriveInput);
+
+    result.append_all(identify_impl(&item.ident, &item.generics, guid));
+    result.append_all(protocol_impl(&item.ident, &item.generics));
+    result.into()
+}
+
+type GuidFields = (u32, u16, u16, u16, u64);
+
+fn identify_impl(ident: &Ident, generics: &Generics, guid: GuidFields) -> TokenStream2 {
+    let (time_low, time_mid, time_high_and_version, clock_seq_and_variant, node) = guid;
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    quote! {
         unsafe impl #impl_generics ::uefi::Identify for #ident #ty_generics #where_clause {
             #[doc(hidden)]
             #[allow(clippy::unreadable_literal)]
@@ -77,11 +114,82 @@ pub fn unsafe_guid(args: TokenStream, input: TokenStream) -> TokenStream {
                 #node,
             );
         }
-    });
-    result.into()
+    }
+}
+
+fn parse_guid_arg(args: TokenStream2) -> Result<GuidFields, TokenStream2> {
+    if let Ok(lit) = syn::parse2::<LitStr>(args.clone()) {
+        parse_guid(lit)
+    } else if let Ok(array) = syn::parse2::<ExprArray>(args.clone()) {
+        parse_guid_bytes(array)
+    } else {
+        Err(err!(
+            args,
+            "Expected a canonical GUID string or an array of 16 bytes"
+        ))
+    }
+}
+
+fn parse_guid_bytes(array: ExprArray) -> Result<GuidFields, TokenStream2> {
+    if array.elems.len() != 16 {
+        return Err(err!(
+            array,
+            "GUID byte array must have 16 elements, found {}",
+            array.elems.len()
+        ));
+    }
+
+    let mut bytes = [0u8; 16];
+    for (byte, elem) in bytes.iter_mut().zip(&array.elems) {
+        *byte = match elem {
+            Expr::Lit(ExprLit {
+                lit: Lit::Int(lit), ..
+            }) => lit
+                .base10_parse()
+                .map_err(|_| err!(lit, "GUID byte \"{}\" does not fit in a u8", lit))?,
+            _ => return Err(err!(elem, "GUID bytes must be integer literals")),
+        };
+    }
+
+    // The first three fields are little endian, the rest is big endian
+    let mut node = [0u8; 8];
+    node[2..].copy_from_slice(&bytes[10..16]);
+    Ok((
+        u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]),
+        u16::from_le_bytes([bytes[4], bytes[5]]),
+        u16::from_le_bytes([bytes[6], bytes[7]]),
+        u16::from_be_bytes([bytes[8], bytes[9]]),
+        u64::from_be_bytes(node),
+    ))
+}
+
+/// Create a `Guid` at compile time.
+///
+/// The argument is a GUID in canonical textual format, and the macro expands
+/// to a constant expression, so it can be used to initialize `const` items:
+///
+/// ```ignore
+/// const ACPI2_GUID: Guid = guid!("8868e871-e4f1-11d3-bc22-0080c73c8881");
+/// ```
+#[proc_macro]
+pub fn guid(args: TokenStream) -> TokenStream {
+    let (time_low, time_mid, time_high_and_version, clock_seq_and_variant, node) =
+        match parse_guid(parse_macro_input!(args as LitStr)) {
+            Ok(data) => data,
+            Err(tokens) => return tokens.into(),
+        };
+
+    quote!(::uefi::Guid::from_values(
+        #time_low,
+        #time_mid,
+        #time_high_and_version,
+        #clock_seq_and_variant,
+        #node,
+    ))
+    .into()
 }
 
-fn parse_guid(guid_lit: LitStr) -> Result<(u32, u16, u16, u16, u64), TokenStream2> {
+fn parse_guid(guid_lit: LitStr) -> Result<GuidFields, TokenStream2> {
     let guid_str = guid_lit.value();
 
     // We expect a canonical GUID string, such as "12345678-9abc-def0-fedc-ba9876543210"
@@ -136,6 +244,57 @@ fn parse_guid(guid_lit: LitStr) -> Result<(u32, u16, u16, u16, u64), TokenStream
     ))
 }
 
+/// Builds a `&'static CStr16` from a string literal at compile time.
+///
+/// Every character must be representable in UCS-2, and the string must not
+/// contain any null characters. A trailing null is added automatically.
+#[proc_macro]
+pub fn cstr16(input: TokenStream) -> TokenStream {
+    let lit = parse_macro_input!(input as LitStr);
+    let value = lit.value();
+
+    // Error spans point to the offending character when the literal contains
+    // no escape sequences, so that byte offsets in the value match the source.
+    let raw_lit = match lit.to_token_stream().into_iter().next().unwrap() {
+        TokenTree::Literal(lit) => lit,
+        _ => unreachable!(),
+    };
+    let has_escapes = raw_lit.to_string() != format!("\"{}\"", value);
+    let char_span = |offset: usize, c: char| {
+        if has_escapes {
+            None
+        } else {
+            raw_lit.subspan(offset + 1..offset + 1 + c.len_utf8())
+        }
+        .unwrap_or_else(|| raw_lit.span())
+    };
+
+    let mut codes = Vec::with_capacity(value.len() + 1);
+    for (offset, c) in value.char_indices() {
+        if c == '\0' {
+            return err!(
+                char_span(offset, c),
+                "CStr16 literal must not contain null characters"
+            )
+            .into();
+        }
+        match u16::try_from(u32::from(c)) {
+            Ok(code) => codes.push(code),
+            Err(_) => {
+                return err!(
+                    char_span(offset, c),
+                    "character {:?} is not representable in UCS-2",
+                    c
+                )
+                .into()
+            }
+        }
+    }
+    codes.push(0);
+
+    quote!(unsafe { ::uefi::CStr16::from_u16_with_nul_unchecked(&[#(#codes),*]) }).into()
+}
+
 /// Custom derive for the `Protocol` trait
 #[proc_macro_derive(Protocol)]
 pub fn derive_protocol(item: TokenStream) -> TokenStream {
@@ -143,9 +302,12 @@ pub fn derive_protocol(item: TokenStream) -> TokenStream {
     let item = parse_macro_input!(item as DeriveInput);
 
     // Then implement Protocol
-    let ident = item.ident.clone();
-    let (impl_generics, ty_generics, where_clause) = item.generics.split_for_impl();
-    let result = quote! {
+    protocol_impl(&item.ident, &item.generics).into()
+}
+
+fn protocol_impl(ident: &Ident, generics: &Generics) -> TokenStream2 {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    quote! {
         // Mark this as a `Protocol` implementation
         impl #impl_generics ::uefi::proto::Protocol for #ident #ty_generics #where_clause {}
 
@@ -154,11 +316,20 @@ pub fn derive_protocol(item: TokenStream) -> TokenStream {
 
         // Most UEFI functions do not support multithreaded access.
         impl #impl_generics !Sync for #ident #ty_generics #where_clause {}
-    };
-    result.into()
+    }
 }
 
 /// Custom attribute for a UEFI executable entrypoint
+///
+/// The entry function must take exactly two arguments, the image `Handle` and
+/// the `SystemTable<Boot>`. It is exported as `efi_main` by default; another
+/// symbol name can be chosen with `#[entry(export_name = "my_main")]`.
+///
+/// The following return types are accepted:
+/// - `Status`, which is returned to the firmware as is.
+/// - `()`, which reports `Status::SUCCESS`.
+/// - `uefi::Result<T, E>`, which reports the status of the completion, or
+///   logs the error and reports its status.
 #[proc_macro_attribute]
 pub fn entry(args: TokenStream, input: TokenStream) -> TokenStream {
     // This code is inspired by the approach in this embedded Rust crate:
@@ -166,11 +337,20 @@ pub fn entry(args: TokenStream, input: TokenStream) -> TokenStream {
 
     let mut errors = TokenStream2::new();
 
+    let mut export_name = LitStr::new("efi_main", Span::call_site());
     if !args.is_empty() {
-        errors.append_all(err!(
-            TokenStream2::from(args),
-            "Entry attribute accepts no arguments"
-        ));
+        let args = TokenStream2::from(args);
+        match syn::parse2::<MetaNameValue>(args.clone()) {
+            Ok(MetaNameValue {
+                path,
+                lit: Lit::Str(name),
+                ..
+            }) if path.is_ident("export_name") => export_name = name,
+            _ => errors.append_all(err!(
+                args,
+                "Entry attribute only accepts an `export_name = \"...\"` argument"
+            )),
+        }
     }
 
     let mut f = parse_macro_input!(input as ItemFn);
@@ -190,25 +370,165 @@ pub fn entry(args: TokenStream, input: TokenStream) -> TokenStream {
             "Entry method should not be generic"
         ));
     }
+    for arg in &f.sig.inputs {
+        if let FnArg::Receiver(receiver) = arg {
+            errors.append_all(err!(receiver, "Entry method should not take `self`"));
+        }
+    }
+    match f.sig.inputs.len() {
+        2 => {}
+        0 | 1 => errors.append_all(err!(
+            f.sig.ident,
+            "Entry method must take exactly two arguments: Handle and SystemTable<Boot>"
+        )),
+        _ => {
+            for extra_arg in f.sig.inputs.iter().skip(2) {
+                errors.append_all(err!(
+                    extra_arg,
+                    "Entry method must take exactly two arguments: Handle and SystemTable<Boot>"
+                ));
+            }
+        }
+    }
 
     // show most errors at once instead of one by one
     if !errors.is_empty() {
         return errors.into();
     }
 
-    // allow the entry function to be unsafe (by moving the keyword around so that it actually works)
-    let unsafety = f.sig.unsafety.take();
+    let return_kind = match entry_return_kind(&f.sig.output) {
+        Ok(kind) => kind,
+        Err(tokens) => return tokens.into(),
+    };
+
+    // Check the type of each argument separately, so that type errors are
+    // reported on the argument that is wrong rather than on the function.
+    let expected_arg_types = [
+        quote!(::uefi::Handle),
+        quote!(::uefi::table::SystemTable<::uefi::table::Boot>),
+    ];
+    let arg_types: Vec<Type> = f
+        .sig
+        .inputs
+        .iter()
+        .map(|arg| match arg {
+            FnArg::Typed(arg) => (*arg.ty).clone(),
+            FnArg::Receiver(_) => unreachable!(),
+        })
+        .collect();
+    let arg_type_checks = arg_types
+        .iter()
+        .zip(&expected_arg_types)
+        .map(|(ty, expected)| {
+            quote_spanned! {ty.span()=>
+                const _: ::core::marker::PhantomData<#expected> = ::core::marker::PhantomData::<#ty>;
+            }
+        });
+    let (image_ty, system_table_ty) = (&arg_types[0], &arg_types[1]);
+
     // strip any visibility modifiers
     f.vis = Visibility::Inherited;
 
-    let ident = &f.sig.ident;
+    let result = if let EntryReturnKind::Status = return_kind {
+        // allow the entry function to be unsafe (by moving the keyword around so that it actually works)
+        let unsafety = f.sig.unsafety.take();
+        let ident = &f.sig.ident;
+
+        quote! {
+            #[export_name = #export_name]
+            #unsafety extern "efiapi" #f
+
+            // typecheck the function pointer (the arguments are checked below)
+            const _: #unsafety extern "efiapi" fn(#image_ty, #system_table_ty) -> ::uefi::Status = #ident;
+
+            #(#arg_type_checks)*
+        }
+    } else {
+        // wrap the entry function in one that has the expected signature, and
+        // converts the return value to a `Status`
+        let unsafety = &f.sig.unsafety;
+        let ident = &f.sig.ident;
+        let call = quote!(#ident(image, system_table));
+        let status = match return_kind {
+            EntryReturnKind::Unit => quote! {
+                #call;
+                ::uefi::Status::SUCCESS
+            },
+            EntryReturnKind::Result => quote! {
+                ::uefi::__private::entry_result_status(#call)
+            },
+            EntryReturnKind::Status => unreachable!(),
+        };
+
+        quote! {
+            #[export_name = #export_name]
+            #unsafety extern "efiapi" fn #ident(
+                image: #image_ty,
+                system_table: #system_table_ty,
+            ) -> ::uefi::Status {
+                #f
 
-    let result = quote! {
-        #[export_name = "efi_main"]
-        #unsafety extern "efiapi" #f
+                #status
+            }
 
-        // typecheck the function pointer
-        const _: #unsafety extern "efiapi" fn(::uefi::Handle, ::uefi::table::SystemTable<::uefi::table::Boot>) -> ::uefi::Status = #ident;
+            #(#arg_type_checks)*
+        }
     };
     result.into()
 }
+
+/// Return types supported by the `entry` macro
+enum EntryReturnKind {
+    /// `Status`, returned to the firmware as is
+    Status,
+    /// `()`, which always reports success
+    Unit,
+    /// `uefi::Result`, which reports the status of the completion or error
+    Result,
+}
+
+fn entry_return_kind(output: &ReturnType) -> Result<EntryReturnKind, TokenStream2> {
+    let ty = match output {
+        ReturnType::Default => return Ok(EntryReturnKind::Unit),
+        ReturnType::Type(_, ty) => ty,
+    };
+
+    match &**ty {
+        Type::Tuple(tuple) if tuple.elems.is_empty() => Ok(EntryReturnKind::Unit),
+        Type::Path(path) if path.qself.is_none() => {
+            match path.path.segments.last().map(|seg| seg.ident.to_string()) {
+                Some(name) if name == "Status" => Ok(EntryReturnKind::Status),
+                Some(name) if name == "Result" => {
+                    // Only `uefi::Result` can be converted to a `Status`, so
+                    // reject other `Result` types early with a clear error.
+                    let segments: Vec<String> = path
+                        .path
+                        .segments
+                        .iter()
+                        .map(|seg| seg.ident.to_string())
+                        .collect();
+                    match segments.as_slice() {
+                        [result] if path.path.leading_colon.is_none() && result == "Result" => {
+                            Ok(EntryReturnKind::Result)
+                        }
+                        [krate, result] if krate == "uefi" && result == "Result" => {
+                            Ok(EntryReturnKind::Result)
+                        }
+                        _ => Err(err!(
+                            ty,
+                            "Entry method must return `uefi::Result`, not another `Result` type"
+                        )),
+                    }
+                }
+                _ => Err(err!(
+                    ty,
+                    "Entry method must return `Status`, `()` or `uefi::Result`"
+                )),
+            }
+        }
+        _ => Err(err!(
+            ty,
+            "Entry method must return `Status`, `()` or `uefi::Result`"
+        )),
+    }
+}
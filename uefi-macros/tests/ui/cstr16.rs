@@ -0,0 +1,13 @@
+use uefi::CStr16;
+use uefi_macros::cstr16;
+
+// This string is OK.
+const GOOD: &CStr16 = cstr16!("EFI\\Boot");
+
+// Fail because of the emoji, which is not representable in UCS-2.
+const BAD_CHAR: &CStr16 = cstr16!("ab😀c");
+
+// Fail because of the interior null.
+const INTERIOR_NUL: &CStr16 = cstr16!("ab\0c");
+
+fn main() {}
@@ -9,11 +9,29 @@ fn good_entry(_handle: Handle, _st: SystemTable<Boot>) -> Status {
     Status::SUCCESS
 }
 
+#[entry(export_name = "good_main")]
+fn good_export_name(_handle: Handle, _st: SystemTable<Boot>) -> Status {
+    Status::SUCCESS
+}
+
+#[entry(export_name = "unit_main")]
+fn good_unit_return(_handle: Handle, _st: SystemTable<Boot>) {}
+
+#[entry(export_name = "result_main")]
+fn good_result_return(_handle: Handle, _st: SystemTable<Boot>) -> uefi::Result<(), usize> {
+    Ok(().into())
+}
+
 #[entry(some_arg)]
 fn bad_attr_arg(_handle: Handle, _st: SystemTable<Boot>) -> Status {
     Status::SUCCESS
 }
 
+#[entry(export_name = 42)]
+fn bad_export_name(_handle: Handle, _st: SystemTable<Boot>) -> Status {
+    Status::SUCCESS
+}
+
 #[entry]
 extern "C" fn bad_abi_modifier(_handle: Handle, _st: SystemTable<Boot>) -> Status {
     Status::SUCCESS
@@ -39,6 +57,24 @@ fn bad_args(_handle: Handle, _st: SystemTable<Boot>, _x: usize) -> bool {
     false
 }
 
+#[entry]
+fn too_few_args(_handle: Handle) -> Status {
+    Status::SUCCESS
+}
+
+#[entry]
+fn bad_arg_types(_st: SystemTable<Boot>, _handle: Handle) -> Status {
+    Status::SUCCESS
+}
+
+#[entry(export_name = "bad_arg_type_main")]
+fn bad_arg_type(_handle: usize, _st: SystemTable<Boot>) {}
+
+#[entry(export_name = "bad_result_main")]
+fn bad_result_type(_handle: Handle, _st: SystemTable<Boot>) -> core::result::Result<(), usize> {
+    Ok(())
+}
+
 #[entry]
 fn bad_return_type(_handle: Handle, _st: SystemTable<Boot>) -> bool {
     false
@@ -1,4 +1,5 @@
-use uefi_macros::unsafe_guid;
+use uefi::Guid;
+use uefi_macros::{guid, unsafe_guid};
 
 // The GUID here is OK.
 #[unsafe_guid("aaaaaaaa-aaaa-aaaa-aaaa-aaaaaaaaaaaa")]
@@ -16,4 +17,26 @@ struct BadHexGroup2;
 #[unsafe_guid("aaaaaaaa-aaaa-aaaa-aaaa-aaaaaaaaaaaG")]
 struct BadHexGroup5;
 
+// The GUID here is OK.
+#[unsafe_guid([0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa])]
+struct GoodBytes;
+
+// Fail because the array has the wrong number of bytes.
+#[unsafe_guid([0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa])]
+struct TooFewBytes;
+
+// Error span should point to the byte that does not fit.
+#[unsafe_guid([0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0x100])]
+struct BadByte;
+
+// Fail because the argument is neither a string nor an array.
+#[unsafe_guid(42)]
+struct BadArg;
+
+// The GUID here is OK.
+const GOOD: Guid = guid!("aaaaaaaa-aaaa-aaaa-aaaa-aaaaaaaaaaaa");
+
+// Error span should point to the third group.
+const BAD_HEX_GROUP_3: Guid = guid!("aaaaaaaa-aaaa-aaGa-aaaa-aaaaaaaaaaaa");
+
 fn main() {}
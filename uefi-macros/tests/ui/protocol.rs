@@ -0,0 +1,20 @@
+#![feature(negative_impls)]
+
+use uefi::proto::Protocol;
+use uefi_macros::protocol;
+
+// The GUID here is OK.
+#[protocol("aaaaaaaa-aaaa-aaaa-aaaa-aaaaaaaaaaaa")]
+struct Good;
+
+// Error span should point to the fourth group.
+#[protocol("aaaaaaaa-aaaa-aaaa-aaGa-aaaaaaaaaaaa")]
+struct BadHexGroup4;
+
+fn is_protocol<P: Protocol>() -> uefi::Guid {
+    P::GUID
+}
+
+fn main() {
+    let _ = is_protocol::<Good>();
+}
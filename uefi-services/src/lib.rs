@@ -145,9 +145,7 @@ fn panic_handler(info: &core::panic::PanicInfo) -> ! {
             location.line(),
             location.column()
         );
-        if let Some(message) = info.message() {
-            error!("{}", message);
-        }
+        error!("{}", info.message());
     }
 
     // Give the user some time to read the message
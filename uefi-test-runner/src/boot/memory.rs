@@ -19,21 +19,30 @@ fn allocate_pages(bt: &BootServices) {
 
     let ty = AllocateType::AnyPages;
     let mem_ty = MemoryType::LOADER_DATA;
-    let pgs = bt
-        .allocate_pages(ty, mem_ty, 1)
-        .expect_success("Failed to allocate a page of memory");
-
-    assert_eq!(pgs % 4096, 0, "Page pointer is not page-aligned");
+    let mut pages = bt
+        .allocate_pages(ty, mem_ty, 4)
+        .expect_success("Failed to allocate pages of memory");
 
-    // Reinterpret the page as an array of bytes
-    let buf = unsafe { &mut *(pgs as *mut [u8; 4096]) };
+    assert_eq!(
+        pages.address() % 4096,
+        0,
+        "Page pointer is not page-aligned"
+    );
+    assert_eq!(pages.len(), 4 * 4096);
 
     // If these don't fail then we properly allocated some memory.
-    buf[0] = 0xF0;
-    buf[4095] = 0x23;
+    pages[0] = 0xF0;
+    pages[4 * 4096 - 1] = 0x23;
+
+    // The pages are freed when dropped.
+    drop(pages);
 
-    // Clean up to avoid memory leaks.
-    bt.free_pages(pgs, 1).unwrap_success();
+    // Pages released from their allocation must be freed manually.
+    let addr = bt
+        .allocate_pages(ty, mem_ty, 1)
+        .expect_success("Failed to allocate a page of memory")
+        .into_raw();
+    bt.free_pages(addr, 1).unwrap_success();
 }
 
 // Simple test to ensure our custom allocator works with the `alloc` crate.
@@ -96,6 +105,12 @@ fn memory_map(bt: &BootServices) {
         .memory_map(&mut buffer)
         .expect_success("Failed to retrieve UEFI memory map");
 
+    let free_bytes = desc_iter
+        .clone()
+        .total_bytes_of_type(MemoryType::CONVENTIONAL);
+    info!("Free memory: {} KiB", free_bytes / 1024);
+    assert!(free_bytes > 0, "No conventional memory is available");
+
     // Collect the descriptors into a vector
     let descriptors = desc_iter.copied().collect::<Vec<_>>();
 
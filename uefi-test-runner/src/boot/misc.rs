@@ -1,11 +1,13 @@
 use core::ffi::c_void;
 use core::ptr::NonNull;
+use core::time::Duration;
 
 use uefi::proto::console::text::Output;
-use uefi::table::boot::{BootServices, EventType, TimerTrigger, Tpl};
-use uefi::{prelude::*, Event};
+use uefi::proto::Protocol;
+use uefi::table::boot::{BootServices, EventType, OpenProtocolParams, TimerTrigger, Tpl};
+use uefi::{prelude::*, unsafe_guid, Event, Identify};
 
-pub fn test(bt: &BootServices) {
+pub fn test(image: Handle, bt: &BootServices) {
     info!("Testing timer...");
     test_timer(bt);
     info!("Testing events...");
@@ -13,16 +15,30 @@ pub fn test(bt: &BootServices) {
     test_callback_with_ctx(bt);
     info!("Testing watchdog...");
     test_watchdog(bt);
+    info!("Testing task priority levels...");
+    test_tpl(bt);
+    info!("Testing protocol installation...");
+    test_install_protocol(image, bt);
+    test_register_protocol_notify(bt);
+}
+
+fn test_tpl(bt: &BootServices) {
+    let _guard = unsafe { bt.raise_tpl(Tpl::NOTIFY) };
+    // The former task priority level is restored when the guard is dropped.
 }
 
 fn test_timer(bt: &BootServices) {
     let timer_event = unsafe { bt.create_event(EventType::TIMER, Tpl::APPLICATION, None, None) }
         .expect_success("Failed to create TIMER event");
     let mut events = unsafe { [timer_event.unsafe_clone()] };
-    bt.set_timer(&timer_event, TimerTrigger::Relative(5_0 /*00 ns */))
+    bt.set_timer(&timer_event, TimerTrigger::relative(Duration::from_secs(1)))
         .expect_success("Failed to set timer");
-    bt.wait_for_event(&mut events)
+    let index = bt
+        .wait_for_event(&mut events)
         .expect_success("Wait for event failed");
+    assert_eq!(index, 0);
+    bt.close_event(timer_event)
+        .expect_success("Failed to close timer event");
 }
 
 fn test_event_callback(bt: &BootServices) {
@@ -64,7 +80,78 @@ fn test_callback_with_ctx(bt: &BootServices) {
 }
 
 fn test_watchdog(bt: &BootServices) {
-    // Disable the UEFI watchdog timer
-    bt.set_watchdog_timer(0, 0x10000, None)
+    // Re-arm the UEFI watchdog timer, then disable it
+    bt.set_watchdog_timer(300, 0x10000, None)
         .expect_success("Could not set watchdog timer");
+    bt.disable_watchdog_timer()
+        .expect_success("Could not disable watchdog timer");
+}
+
+/// A dummy protocol installed by the test runner.
+#[unsafe_guid("1a972918-3f69-4b5d-8cb4-cece2309c7f5")]
+#[derive(Protocol)]
+struct TestProtocol {
+    data: u32,
+}
+
+fn test_install_protocol(image: Handle, bt: &BootServices) {
+    let mut test_protocol = TestProtocol { data: 123 };
+    let interface = &mut test_protocol as *mut TestProtocol as *mut c_void;
+
+    let handle = unsafe { bt.install_protocol_interface(None, &TestProtocol::GUID, interface) }
+        .expect_success("Failed to install protocol interface");
+
+    let handles = bt
+        .find_handles::<TestProtocol>()
+        .expect_success("Failed to find handles of the test protocol");
+    assert_eq!(handles, [handle]);
+
+    {
+        let protocol = bt
+            .open_protocol_shared::<TestProtocol>(OpenProtocolParams {
+                handle,
+                agent: image,
+                controller: None,
+            })
+            .expect_success("Failed to open the test protocol");
+        let protocol = unsafe { &*protocol.interface.get() };
+        assert_eq!(protocol.data, 123);
+    }
+
+    unsafe { bt.uninstall_protocol_interface(handle, &TestProtocol::GUID, interface) }
+        .expect_success("Failed to uninstall protocol interface");
+    assert!(bt.find_handles::<TestProtocol>().is_err());
+}
+
+fn test_register_protocol_notify(bt: &BootServices) {
+    let event = unsafe { bt.create_event(EventType::empty(), Tpl::CALLBACK, None, None) }
+        .expect_success("Failed to create event");
+    let (event, search_type) = bt
+        .register_protocol_notify(&TestProtocol::GUID, event)
+        .expect_success("Failed to register protocol notify");
+
+    // Nothing was installed yet.
+    assert!(!bt
+        .check_event(unsafe { event.unsafe_clone() })
+        .expect_success("Failed to check event"));
+
+    let mut test_protocol = TestProtocol { data: 456 };
+    let interface = &mut test_protocol as *mut TestProtocol as *mut c_void;
+    let handle = unsafe { bt.install_protocol_interface(None, &TestProtocol::GUID, interface) }
+        .expect_success("Failed to install protocol interface");
+
+    // The event is signaled, and the new handle can be found with the
+    // search key.
+    assert!(bt
+        .check_event(unsafe { event.unsafe_clone() })
+        .expect_success("Failed to check event"));
+    let handles = bt
+        .locate_handle_buffer(search_type)
+        .expect_success("Failed to locate the new handle");
+    assert_eq!(*handles, [handle]);
+
+    unsafe { bt.uninstall_protocol_interface(handle, &TestProtocol::GUID, interface) }
+        .expect_success("Failed to uninstall protocol interface");
+    bt.close_event(event)
+        .expect_success("Failed to close event");
 }
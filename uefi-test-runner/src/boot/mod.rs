@@ -1,9 +1,50 @@
-use uefi::table::boot::BootServices;
+use core::ptr;
+use uefi::prelude::*;
+use uefi::proto::loaded_image::LoadedImage;
+use uefi::table::boot::{BootServices, OpenProtocolParams};
+use uefi::Handle;
 
-pub fn test(bt: &BootServices) {
+pub fn test(image: Handle, bt: &BootServices) {
     info!("Testing boot services");
     memory::test(bt);
-    misc::test(bt);
+    misc::test(image, bt);
+    test_load_image(image, bt);
+    test_open_protocol_shared(image, bt);
+}
+
+fn test_load_image(image: Handle, bt: &BootServices) {
+    info!("Testing image loading...");
+
+    // A buffer which does not contain a PE image must be rejected.
+    let status = bt
+        .load_image_from_buffer(image, &[0; 64])
+        .expect_error("Loading an invalid image should fail")
+        .status();
+    assert!(
+        status == Status::LOAD_ERROR || status == Status::UNSUPPORTED,
+        "Unexpected status when loading an invalid image: {:?}",
+        status
+    );
+}
+
+fn test_open_protocol_shared(image: Handle, bt: &BootServices) {
+    info!("Testing shared protocol opening...");
+
+    let params = || OpenProtocolParams {
+        handle: image,
+        agent: image,
+        controller: None,
+    };
+
+    // Unlike exclusive mode, shared mode allows opening the same protocol
+    // interface more than once.
+    let first = bt
+        .open_protocol_shared::<LoadedImage>(params())
+        .expect_success("Failed to open LoadedImage protocol");
+    let second = bt
+        .open_protocol_shared::<LoadedImage>(params())
+        .expect_success("Failed to open LoadedImage protocol a second time");
+    assert!(ptr::eq(first.interface.get(), second.interface.get()));
 }
 
 mod memory;
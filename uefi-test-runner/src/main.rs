@@ -1,16 +1,20 @@
 #![no_std]
 #![no_main]
 #![feature(abi_efiapi)]
+#![feature(negative_impls)]
 
 #[macro_use]
 extern crate log;
 #[macro_use]
 extern crate alloc;
 
-use alloc::string::String;
+use alloc::string::ToString;
+use core::fmt::Write;
+use core::time::Duration;
 use uefi::prelude::*;
 use uefi::proto::console::serial::Serial;
-use uefi::table::boot::{OpenProtocolAttributes, OpenProtocolParams};
+use uefi::table::boot::OpenProtocolParams;
+use uefi::table::Revision;
 
 mod boot;
 mod proto;
@@ -24,9 +28,8 @@ fn efi_main(image: Handle, mut st: SystemTable<Boot>) -> Status {
     // unit tests here
 
     // output firmware-vendor (CStr16 to Rust string)
-    let mut buf = String::new();
-    st.firmware_vendor().as_str_in_buf(&mut buf).unwrap();
-    info!("Firmware Vendor: {}", buf.as_str());
+    let vendor = st.firmware_vendor().to_string();
+    info!("Firmware Vendor: {}", vendor.as_str());
 
     // Reset the console before running all the other tests.
     st.stdout()
@@ -44,7 +47,7 @@ fn efi_main(image: Handle, mut st: SystemTable<Boot>) -> Status {
         .expect("Failed to retrieve boot file system")
         .unwrap();
 
-    boot::test(bt);
+    boot::test(image, bt);
 
     // Test all the supported protocols.
     proto::test(image, &mut st);
@@ -58,15 +61,12 @@ fn efi_main(image: Handle, mut st: SystemTable<Boot>) -> Status {
     shutdown(image, st);
 }
 
-fn check_revision(rev: uefi::table::Revision) {
-    let (major, minor) = (rev.major(), rev.minor());
+fn check_revision(rev: Revision) {
+    info!("UEFI {}", rev);
 
-    info!("UEFI {}.{}", major, minor / 10);
-
-    assert!(major >= 2, "Running on an old, unsupported version of UEFI");
     assert!(
-        minor >= 30,
-        "Old version of UEFI 2, some features might not be available."
+        rev >= Revision::EFI_2_30,
+        "Old version of UEFI, some features might not be available."
     );
 }
 
@@ -90,14 +90,11 @@ fn check_screenshot(image: Handle, bt: &BootServices, name: &str) {
             .expect("Second serial device is missing");
 
         let serial = bt
-            .open_protocol::<Serial>(
-                OpenProtocolParams {
-                    handle: serial_handle,
-                    agent: image,
-                    controller: None,
-                },
-                OpenProtocolAttributes::Exclusive,
-            )
+            .open_protocol_exclusive::<Serial>(OpenProtocolParams {
+                handle: serial_handle,
+                agent: image,
+                controller: None,
+            })
             .expect_success("Could not open serial protocol");
         let serial = unsafe { &mut *serial.interface.get() };
 
@@ -109,25 +106,22 @@ fn check_screenshot(image: Handle, bt: &BootServices, name: &str) {
             .expect_success("Failed to configure serial port timeout");
 
         // Send a screenshot request to the host
-        serial
-            .write(b"SCREENSHOT: ")
-            .expect_success("Failed to send request");
-        let name_bytes = name.as_bytes();
-        serial
-            .write(name_bytes)
-            .expect_success("Failed to send request");
-        serial.write(b"\n").expect_success("Failed to send request");
+        writeln!(serial, "SCREENSHOT: {}", name).expect("Failed to send request");
 
         // Wait for the host's acknowledgement before moving forward
-        let mut reply = [0; 3];
-        serial
-            .read(&mut reply[..])
+        let mut reply = [0; 16];
+        let reply_len = serial
+            .read_until(b'\n', &mut reply)
             .expect_success("Failed to read host reply");
 
-        assert_eq!(&reply[..], b"OK\n", "Unexpected screenshot request reply");
+        assert_eq!(
+            &reply[..reply_len],
+            b"OK\n",
+            "Unexpected screenshot request reply"
+        );
     } else {
         // Outside of QEMU, give the user some time to inspect the output
-        bt.stall(3_000_000);
+        bt.stall_duration(Duration::from_secs(3));
     }
 }
 
@@ -140,7 +134,7 @@ fn shutdown(image: uefi::Handle, mut st: SystemTable<Boot>) -> ! {
     // Inform the user, and give him time to read on real hardware
     if cfg!(not(feature = "qemu")) {
         info!("Testing complete, shutting down in 3 seconds...");
-        st.boot_services().stall(3_000_000);
+        st.boot_services().stall_duration(Duration::from_secs(3));
     } else {
         info!("Testing complete, shutting down...");
     }
@@ -1,5 +1,7 @@
 use uefi::prelude::*;
-use uefi::proto::console::gop::{BltOp, BltPixel, FrameBuffer, GraphicsOutput, PixelFormat};
+use uefi::proto::console::gop::{
+    BltOp, BltPixel, BltRegion, FrameBuffer, GraphicsOutput, PixelFormat,
+};
 use uefi::table::boot::BootServices;
 
 pub fn test(image: Handle, bt: &BootServices) {
@@ -8,6 +10,7 @@ pub fn test(image: Handle, bt: &BootServices) {
         let gop = gop.expect("Warnings encountered while opening GOP");
         let gop = unsafe { &mut *gop.get() };
 
+        set_largest_mode(gop);
         set_graphics_mode(gop);
         fill_color(gop);
         draw_fb(gop);
@@ -19,6 +22,35 @@ pub fn test(image: Handle, bt: &BootServices) {
     }
 }
 
+// Switch to the largest mode with 32-bit pixels.
+fn set_largest_mode(gop: &mut GraphicsOutput) {
+    let mode = gop
+        .modes()
+        .map(|mode| mode.expect("Warnings encountered while querying mode"))
+        .filter(|mode| {
+            matches!(
+                mode.info().pixel_format(),
+                PixelFormat::Rgb | PixelFormat::Bgr
+            )
+        })
+        .max_by_key(|mode| {
+            let (width, height) = mode.info().resolution();
+            width * height
+        })
+        .expect("No 32-bit graphics mode");
+    info!("Largest graphics mode: {:?}", mode);
+
+    gop.set_mode(&mode)
+        .expect_success("Failed to set graphics mode");
+    let info = gop.current_mode_info();
+    assert_eq!(info.resolution(), mode.info().resolution());
+    assert_eq!(info.stride(), mode.info().stride());
+
+    // The frame buffer must cover the new resolution.
+    let fb_info = gop.frame_buffer_info();
+    assert!(fb_info.size >= fb_info.stride * fb_info.height * 4);
+}
+
 // Set a larger graphics mode.
 fn set_graphics_mode(gop: &mut GraphicsOutput) {
     // We know for sure QEMU has a 1024x768 mode.
@@ -46,6 +78,32 @@ fn fill_color(gop: &mut GraphicsOutput) {
 
     gop.blt(op)
         .expect_success("Failed to fill screen with color");
+
+    // Read a pixel back from the screen.
+    let mut pixel = [BltPixel::new(0, 0, 0)];
+    gop.blt(BltOp::VideoToBltBuffer {
+        buffer: &mut pixel,
+        src: (512, 384),
+        dest: BltRegion::Full,
+        dims: (1, 1),
+    })
+    .expect_success("Failed to read pixel from screen");
+    assert_eq!(
+        (pixel[0].red, pixel[0].green, pixel[0].blue),
+        (100, 149, 237)
+    );
+
+    // Rectangles which don't fit on the screen are rejected.
+    let status = gop
+        .blt(BltOp::VideoToBltBuffer {
+            buffer: &mut pixel,
+            src: (1024, 0),
+            dest: BltRegion::Full,
+            dims: (1, 1),
+        })
+        .expect_error("Out-of-bounds blt should fail")
+        .status();
+    assert_eq!(status, Status::INVALID_PARAMETER);
 }
 
 // Draw directly to the frame buffer.
@@ -54,13 +112,18 @@ fn draw_fb(gop: &mut GraphicsOutput) {
     let stride = mi.stride();
     let (width, height) = mi.resolution();
 
+    let fb_info = gop.frame_buffer_info();
+    assert_eq!((fb_info.width, fb_info.height), (width, height));
+    assert_eq!(fb_info.stride, stride);
+    assert_eq!(fb_info.pixel_format, mi.pixel_format());
+
     let mut fb = gop.frame_buffer();
 
-    type PixelWriter = unsafe fn(&mut FrameBuffer, usize, [u8; 3]);
-    unsafe fn write_pixel_rgb(fb: &mut FrameBuffer, pixel_base: usize, rgb: [u8; 3]) {
+    type PixelWriter = fn(&mut FrameBuffer, usize, [u8; 3]);
+    fn write_pixel_rgb(fb: &mut FrameBuffer, pixel_base: usize, rgb: [u8; 3]) {
         fb.write_value(pixel_base, rgb);
     }
-    unsafe fn write_pixel_bgr(fb: &mut FrameBuffer, pixel_base: usize, rgb: [u8; 3]) {
+    fn write_pixel_bgr(fb: &mut FrameBuffer, pixel_base: usize, rgb: [u8; 3]) {
         fb.write_value(pixel_base, [rgb[2], rgb[1], rgb[0]]);
     }
     let write_pixel: PixelWriter = match mi.pixel_format() {
@@ -77,15 +140,37 @@ fn draw_fb(gop: &mut GraphicsOutput) {
         assert!((y1 < height) && (y2 < height), "Bad Y coordinate");
         for row in y1..y2 {
             for column in x1..x2 {
-                unsafe {
-                    let pixel_index = (row * stride) + column;
-                    let pixel_base = 4 * pixel_index;
-                    write_pixel(&mut fb, pixel_base, color);
-                }
+                let pixel_index = (row * stride) + column;
+                let pixel_base = 4 * pixel_index;
+                write_pixel(&mut fb, pixel_base, color);
             }
         }
     };
 
     fill_rectangle((50, 30), (150, 600), [250, 128, 64]);
     fill_rectangle((400, 120), (750, 450), [16, 128, 255]);
+
+    // Write a pixel through the frame buffer, and read it back with blt.
+    let original = read_pixel(gop, (0, 0));
+    let mut fb = gop.frame_buffer();
+    write_pixel(&mut fb, 0, [10, 20, 30]);
+    let pixel = read_pixel(gop, (0, 0));
+    assert_eq!((pixel.red, pixel.green, pixel.blue), (10, 20, 30));
+
+    // Restore the pixel so that the screenshot matches.
+    let mut fb = gop.frame_buffer();
+    write_pixel(&mut fb, 0, [original.red, original.green, original.blue]);
+}
+
+// Read a single pixel from the screen.
+fn read_pixel(gop: &mut GraphicsOutput, pos: (usize, usize)) -> BltPixel {
+    let mut pixel = [BltPixel::new(0, 0, 0)];
+    gop.blt(BltOp::VideoToBltBuffer {
+        buffer: &mut pixel,
+        src: pos,
+        dest: BltRegion::Full,
+        dims: (1, 1),
+    })
+    .expect_success("Failed to read pixel from screen");
+    pixel[0]
 }
@@ -3,15 +3,18 @@ use uefi::prelude::*;
 pub fn test(image: Handle, st: &mut SystemTable<Boot>) {
     info!("Testing console protocols");
 
+    stdin::test(st.stdin());
     stdout::test(st.stdout());
 
     let bt = st.boot_services();
     serial::test(bt);
     gop::test(image, bt);
     pointer::test(bt);
+    pointer::test_absolute(bt);
 }
 
 mod gop;
 mod pointer;
 mod serial;
+mod stdin;
 mod stdout;
@@ -1,5 +1,5 @@
 use uefi::prelude::*;
-use uefi::proto::console::pointer::Pointer;
+use uefi::proto::console::pointer::{AbsolutePointer, Pointer};
 use uefi::table::boot::BootServices;
 
 pub fn test(bt: &BootServices) {
@@ -12,6 +12,15 @@ pub fn test(bt: &BootServices) {
             .reset(false)
             .expect_success("Failed to reset pointer device");
 
+        info!("Pointer mode: {:?}", pointer.mode());
+
+        // Polling the input event must not block.
+        let event = unsafe { pointer.wait_for_input_event().unsafe_clone() };
+        let signaled = bt
+            .check_event(event)
+            .expect_success("Failed to check pointer input event");
+        info!("Pointer input event signaled: {}", signaled);
+
         let state = pointer
             .read_state()
             .expect_success("Failed to retrieve pointer state");
@@ -25,3 +34,33 @@ pub fn test(bt: &BootServices) {
         warn!("No pointer device found");
     }
 }
+
+pub fn test_absolute(bt: &BootServices) {
+    info!("Running absolute pointer protocol test");
+    if let Ok(pointer) = bt.locate_protocol::<AbsolutePointer>() {
+        let pointer =
+            pointer.expect("Warnings encountered while opening absolute pointer protocol");
+        let pointer = unsafe { &mut *pointer.get() };
+
+        pointer
+            .reset(false)
+            .expect_success("Failed to reset absolute pointer device");
+
+        let mode = *pointer.mode();
+        info!("Absolute pointer mode: {:?}", mode);
+        assert!(mode.absolute_max.0 > mode.absolute_min.0);
+        assert!(mode.absolute_max.1 > mode.absolute_min.1);
+
+        let state = pointer
+            .get_state()
+            .expect_success("Failed to retrieve absolute pointer state");
+
+        if let Some(state) = state {
+            info!("New absolute pointer state: {:#?}", state);
+        } else {
+            info!("Absolute pointer state has not changed since the last query");
+        }
+    } else {
+        warn!("No absolute pointer device found");
+    }
+}
@@ -1,3 +1,4 @@
+use core::fmt::Write;
 use uefi::prelude::*;
 use uefi::proto::console::serial::{ControlBits, Serial};
 use uefi::table::boot::BootServices;
@@ -16,6 +17,11 @@ pub fn test(bt: &BootServices) {
         let old_ctrl_bits = serial
             .get_control_bits()
             .expect_success("Failed to get device control bits");
+        info!(
+            "Serial device control bits: {:?} (supported: {:?})",
+            old_ctrl_bits,
+            serial.io_mode().control_mask
+        );
         let mut ctrl_bits = ControlBits::empty();
 
         // For the purposes of testing, we're _not_ going to implement
@@ -44,6 +50,23 @@ pub fn test(bt: &BootServices) {
 
         assert_eq!(OUTPUT, &input[..]);
 
+        // Exercise the `fmt::Write` implementation and the line-based reader.
+        writeln!(serial, "Hi {}", 42).expect("Failed to format to serial port");
+
+        let mut input = [0u8; MSG_LEN];
+        let len = serial
+            .read_until(b'\n', &mut input)
+            .expect_success("Failed to read line from serial port");
+        assert_eq!(&input[..len], b"Hi 42\n");
+
+        serial
+            .write(OUTPUT)
+            .expect_success("Failed to write to serial port");
+        serial
+            .read_exact(&mut input)
+            .expect_success("Failed to read from serial port");
+        assert_eq!(OUTPUT, &input[..]);
+
         // Clean up after ourselves
         serial
             .reset()
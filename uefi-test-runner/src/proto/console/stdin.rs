@@ -0,0 +1,15 @@
+use uefi::prelude::*;
+use uefi::proto::console::text::Input;
+
+pub fn test(stdin: &mut Input) {
+    info!("Running text input protocol test");
+
+    // Discard any keys which were pressed before the test started.
+    stdin
+        .reset(false)
+        .expect_success("Failed to reset text input device");
+
+    // Reading a key must not block when none is pending.
+    let key = stdin.read_key().expect_success("Failed to poll for a key");
+    assert_eq!(key, None, "No key should be pending after a reset");
+}
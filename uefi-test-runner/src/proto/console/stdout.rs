@@ -1,3 +1,4 @@
+use core::fmt::Write;
 use uefi::prelude::*;
 use uefi::proto::console::text::{Color, Output};
 
@@ -8,6 +9,7 @@ pub fn test(stdout: &mut Output) {
     change_text_mode(stdout);
     change_color(stdout);
     center_text(stdout);
+    write_formatted(stdout);
 
     // Print all modes.
     for (index, mode) in stdout.modes().enumerate() {
@@ -30,16 +32,37 @@ fn get_current_mode(stdout: &mut Output) {
     info!("UEFI standard output current mode: {:?}", current_mode);
 }
 
-// Switch to the maximum supported text mode.
+// Switch to the text mode with the most rows.
 fn change_text_mode(stdout: &mut Output) {
     let best_mode = stdout
         .modes()
-        .last()
-        .unwrap()
-        .expect("Warnings encountered while querying text mode");
+        .map(|mode| mode.expect("Warnings encountered while querying text mode"))
+        .max_by_key(|mode| mode.rows())
+        .unwrap();
     stdout
         .set_mode(best_mode)
         .expect_success("Failed to change text mode");
+
+    let current_mode = stdout
+        .current_mode()
+        .expect_success("Failed to get current text mode")
+        .expect("No text mode set after changing it");
+    assert_eq!(current_mode, best_mode, "Text mode did not change");
+    info!(
+        "Switched to text mode #{}: {} rows by {} columns",
+        current_mode.index(),
+        current_mode.rows(),
+        current_mode.columns()
+    );
+}
+
+// Print formatted text with the `fmt::Write` implementation.
+fn write_formatted(stdout: &mut Output) {
+    writeln!(stdout, "Formatted output: {}", 42).expect("Failed to write formatted text");
+
+    // Longer strings are converted and written in several chunks.
+    let long_line = ["0123456789"; 30].concat();
+    writeln!(stdout, "{}\n{}", long_line, long_line).expect("Failed to write a long string");
 }
 
 // Set a new color, and paint the background with it.
@@ -1,6 +1,10 @@
+use alloc::vec::Vec;
+use core::slice;
 use uefi::prelude::*;
-use uefi::proto::device_path::DevicePath;
+use uefi::proto::device_path::text::DevicePathToText;
+use uefi::proto::device_path::{DevicePath, DeviceSubType, DeviceType};
 use uefi::proto::loaded_image::LoadedImage;
+use uefi::proto::media::fs::SimpleFileSystem;
 use uefi::table::boot::{BootServices, OpenProtocolAttributes, OpenProtocolParams};
 
 pub fn test(image: Handle, bt: &BootServices) {
@@ -38,4 +42,63 @@ pub fn test(image: Handle, bt: &BootServices) {
             path.length(),
         );
     }
+
+    test_locate_device_path(bt, loaded_image.device(), device_path);
+
+    if let Ok(device_path_to_text) = bt.locate_protocol::<DevicePathToText>() {
+        let device_path_to_text = device_path_to_text
+            .expect("Warnings encountered while opening DevicePathToText protocol");
+        let device_path_to_text = unsafe { &*device_path_to_text.get() };
+
+        for path in device_path.iter() {
+            let text = device_path_to_text
+                .convert_device_node_to_text(bt, path, true, false)
+                .expect_success("Failed to convert device node to text");
+            info!("node: {}", &*text);
+        }
+
+        let text = device_path_to_text
+            .convert_device_path_to_text(bt, device_path, true, false)
+            .expect_success("Failed to convert device path to text");
+        info!("path: {}", &*text);
+    } else {
+        warn!("DevicePathToText protocol is not supported");
+    }
+}
+
+fn test_locate_device_path(bt: &BootServices, device: Handle, device_path: &DevicePath) {
+    info!("Testing locate_device_path");
+
+    // Build the full device path of a file on the image's device, by
+    // appending a file path node to the device's path.
+    let mut path_bytes = Vec::new();
+    for node in device_path.iter() {
+        let node_bytes = unsafe {
+            slice::from_raw_parts(
+                node as *const DevicePath as *const u8,
+                usize::from(node.length()),
+            )
+        };
+        path_bytes.extend_from_slice(node_bytes);
+    }
+    let file_name: Vec<u8> = "\\EFI\\test.txt\0"
+        .encode_utf16()
+        .flat_map(u16::to_le_bytes)
+        .collect();
+    let file_node_len = (4 + file_name.len()) as u16;
+    path_bytes.extend_from_slice(&[DeviceType::MEDIA.0, DeviceSubType::MEDIA_FILE_PATH.0]);
+    path_bytes.extend_from_slice(&file_node_len.to_le_bytes());
+    path_bytes.extend_from_slice(&file_name);
+    path_bytes.extend_from_slice(&[DeviceType::END.0, DeviceSubType::END_ENTIRE.0, 4, 0]);
+
+    // The file system is provided by the image's device, and the remaining
+    // path is the file path node.
+    let mut path = unsafe { &*(path_bytes.as_ptr() as *const DevicePath) };
+    let handle = bt
+        .locate_device_path::<SimpleFileSystem>(&mut path)
+        .expect_success("Failed to locate SimpleFileSystem on device path");
+    assert_eq!(handle, device);
+    assert_eq!(path.device_type(), DeviceType::MEDIA);
+    assert_eq!(path.sub_type(), DeviceSubType::MEDIA_FILE_PATH);
+    assert_eq!(path.data(), &file_name[..]);
 }
@@ -20,6 +20,11 @@ pub fn test(image: Handle, bt: &BootServices) {
     let load_options = loaded_image.load_options_as_bytes();
     info!("LoadedImage options: {:?}", load_options);
 
+    match loaded_image.load_options_as_cstr16() {
+        Ok(load_options) => info!("LoadedImage options as string: {}", load_options),
+        Err(err) => info!("LoadedImage options are not a string: {:?}", err),
+    }
+
     let (image_base, image_size) = loaded_image.info();
     info!(
         "LoadedImage image address: {:?}, image size: {} bytes",
@@ -1,5 +1,9 @@
 use uefi::prelude::*;
-use uefi::proto::media::file::{Directory, File, FileAttribute, FileMode, FileType};
+use uefi::proto::media::block::BlockIO;
+use uefi::proto::media::disk::DiskIO;
+use uefi::proto::media::file::{
+    Directory, File, FileAttribute, FileInfo, FileMode, FileSystemInfo, FileType,
+};
 use uefi::proto::media::fs::SimpleFileSystem;
 use uefi::proto::media::partition::PartitionInfo;
 use uefi::table::boot::{OpenProtocolAttributes, OpenProtocolParams};
@@ -19,6 +23,12 @@ pub fn test_open_and_read(directory: &mut Directory) {
                 let buffer = &buffer[..size];
                 info!("Successfully read {}", test_input_path);
                 assert_eq!(buffer, b"test input data");
+
+                file.set_position(0).unwrap_success();
+                let contents = file
+                    .read_to_end()
+                    .expect_success(&format!("failed to read all of {}", test_input_path));
+                assert_eq!(contents, b"test input data");
             } else {
                 panic!("{} is not a regular file", test_input_path);
             }
@@ -36,6 +46,137 @@ pub fn test_open_and_read(directory: &mut Directory) {
     }
 }
 
+/// Open the test file in the boot directory, one path component at a time.
+fn test_open_path(directory: &mut Directory) {
+    let test_input_path = CString16::try_from("\\EFI\\BOOT\\test_input.txt").unwrap();
+    match directory.open_path(&test_input_path, FileMode::Read, FileAttribute::empty()) {
+        Ok(file) => {
+            let file = file.unwrap().into_type().unwrap_success();
+            if let FileType::Regular(mut file) = file {
+                let contents = file
+                    .read_to_end()
+                    .expect_success(&format!("failed to read {}", test_input_path));
+                assert_eq!(contents, b"test input data");
+            } else {
+                panic!("{} is not a regular file", test_input_path);
+            }
+        }
+        Err(err) => {
+            let msg = format!("Failed to open {}: {:?}", test_input_path, err);
+            // As in `test_open_and_read`, only panic on failure under qemu.
+            if cfg!(feature = "qemu") {
+                panic!("{}", msg);
+            } else {
+                warn!("{}", msg);
+            }
+        }
+    }
+}
+
+/// Query information about the root directory and its volume.
+fn test_boxed_info(directory: &mut Directory) {
+    let info = directory
+        .get_boxed_info::<FileInfo>()
+        .expect_success("Failed to get file info of the root directory");
+    info!("Root directory info: {:?}", info);
+    assert!(info.attribute().contains(FileAttribute::DIRECTORY));
+
+    let fs_info = directory
+        .get_boxed_info::<FileSystemInfo>()
+        .expect_success("Failed to get file system info");
+    info!(
+        "Volume \"{}\": {} of {} bytes free",
+        fs_info.volume_label(),
+        fs_info.free_space(),
+        fs_info.volume_size()
+    );
+    assert!(fs_info.free_space() <= fs_info.volume_size());
+}
+
+/// Read the first block of each block device.
+fn test_block_io(image: Handle, bt: &BootServices) {
+    let handles = bt
+        .find_handles::<BlockIO>()
+        .expect_success("Failed to get handles for `BlockIO` protocol");
+    assert!(!handles.is_empty(), "No block devices found");
+
+    for handle in handles {
+        let block_io = bt
+            .open_protocol::<BlockIO>(
+                OpenProtocolParams {
+                    handle,
+                    agent: image,
+                    controller: None,
+                },
+                OpenProtocolAttributes::GetProtocol,
+            )
+            .expect_success("Failed to open `BlockIO` protocol");
+        let block_io = unsafe { &*block_io.interface.get() };
+        let media = block_io.media();
+        if !media.is_media_preset() {
+            continue;
+        }
+
+        let block_size = media.block_size() as usize;
+        let mut buffer = vec![0; block_size + 1];
+        block_io
+            .read_blocks(media.media_id(), 0, &mut buffer[..block_size])
+            .expect_success("Failed to read the first block");
+        info!(
+            "Read {} bytes from LBA 0 of a device with {} blocks",
+            block_size,
+            media.last_block() + 1
+        );
+
+        // The buffer must hold a whole number of blocks.
+        let status = block_io
+            .read_blocks(media.media_id(), 0, &mut buffer)
+            .expect_error("Reading a partial block should fail")
+            .status();
+        assert_eq!(status, Status::BAD_BUFFER_SIZE);
+    }
+}
+
+/// Read the boot signature at the end of the first sector of each disk.
+fn test_disk_io(image: Handle, bt: &BootServices) {
+    let handles = bt
+        .find_handles::<DiskIO>()
+        .expect_success("Failed to get handles for `DiskIO` protocol");
+
+    let mut found_signature = false;
+    for handle in handles {
+        let params = || OpenProtocolParams {
+            handle,
+            agent: image,
+            controller: None,
+        };
+        // The media ID is only available through the Block I/O protocol,
+        // which is always installed alongside Disk I/O.
+        let block_io = bt
+            .open_protocol::<BlockIO>(params(), OpenProtocolAttributes::GetProtocol)
+            .expect_success("Failed to open `BlockIO` protocol");
+        let media = unsafe { &*block_io.interface.get() }.media();
+        if !media.is_media_preset() {
+            continue;
+        }
+
+        let disk_io = bt
+            .open_protocol::<DiskIO>(params(), OpenProtocolAttributes::GetProtocol)
+            .expect_success("Failed to open `DiskIO` protocol");
+        let disk_io = unsafe { &*disk_io.interface.get() };
+
+        let mut signature = [0; 2];
+        disk_io
+            .read_disk(media.media_id(), 510, &mut signature)
+            .expect_success("Failed to read from disk");
+        info!("Boot signature: {:02x?}", signature);
+        found_signature |= signature == [0x55, 0xaa];
+    }
+
+    // Both the MBR and the FAT boot sector of the boot disk are signed.
+    assert!(found_signature, "No disk has a boot signature");
+}
+
 pub fn test(image: Handle, bt: &BootServices) {
     info!("Testing Media Access protocols");
 
@@ -65,11 +206,30 @@ pub fn test(image: Handle, bt: &BootServices) {
         }
         directory.reset_entry_readout().unwrap().unwrap();
 
+        let mut entries = directory
+            .entries()
+            .expect_success("Failed to enumerate the root directory");
+        let mut count = 0;
+        while let Some(entry) = entries
+            .next_entry()
+            .expect_success("Failed to read a directory entry")
+        {
+            info!("Root directory entry: {}", entry.file_name());
+            count += 1;
+        }
+        assert!(count > 0, "The root directory has no entries");
+        directory.reset_entry_readout().unwrap().unwrap();
+
         test_open_and_read(&mut directory);
+        test_open_path(&mut directory);
+        test_boxed_info(&mut directory);
     } else {
         warn!("`SimpleFileSystem` protocol is not available");
     }
 
+    test_block_io(image, bt);
+    test_disk_io(image, bt);
+
     let handles = bt
         .find_handles::<PartitionInfo>()
         .expect_success("Failed to get handles for `PartitionInfo` protocol");
@@ -0,0 +1,32 @@
+use uefi::prelude::*;
+use uefi::proto::misc::Timestamp;
+
+pub fn test(bt: &BootServices) {
+    info!("Testing miscellaneous protocols");
+
+    test_timestamp(bt);
+}
+
+fn test_timestamp(bt: &BootServices) {
+    info!("Running timestamp protocol test");
+    if let Ok(timestamp) = bt.locate_protocol::<Timestamp>() {
+        let timestamp = timestamp.expect("Warnings encountered while opening timestamp protocol");
+        let timestamp = unsafe { &*timestamp.get() };
+
+        let properties = timestamp
+            .get_properties()
+            .expect_success("Failed to get timestamp properties");
+        info!("Timestamp properties: {:?}", properties);
+
+        let start = timestamp.get_timestamp();
+        bt.stall(1000);
+        let end = timestamp.get_timestamp();
+
+        let elapsed = properties
+            .elapsed_nanos(start, end)
+            .expect("Timestamp frequency is zero");
+        info!("Stalling for 1ms took {}ns", elapsed);
+    } else {
+        warn!("Timestamp protocol is not supported");
+    }
+}
@@ -1,6 +1,7 @@
 use uefi::prelude::*;
 
 use uefi::proto::loaded_image::LoadedImage;
+use uefi::table::boot::SearchType;
 use uefi::{proto, Identify};
 
 pub fn test(image: Handle, st: &mut SystemTable<Boot>) {
@@ -11,12 +12,23 @@ pub fn test(image: Handle, st: &mut SystemTable<Boot>) {
     let bt = st.boot_services();
     find_protocol(bt);
     test_protocols_per_handle(image, bt);
+    test_locate_handle_buffer(bt);
 
     debug::test(image, bt);
     device_path::test(image, bt);
     loaded_image::test(image, bt);
     media::test(image, bt);
+    misc::test(bt);
+    network::test(bt);
+    network::test_pxe(bt);
+    network::test_ip4config2(bt);
+    network::test_dns(image, bt);
     pi::test(bt);
+    rng::test(bt);
+    security::test(bt);
+    shell::test(bt);
+    shell::test_params(image, bt);
+    string::test(bt);
 
     #[cfg(any(
         target_arch = "i386",
@@ -54,12 +66,37 @@ fn test_protocols_per_handle(image: Handle, bt: &BootServices) {
         .any(|guid| **guid == LoadedImage::GUID));
 }
 
+fn test_locate_handle_buffer(bt: &BootServices) {
+    info!("Testing locate_handle_buffer");
+
+    let all_handles = bt
+        .locate_handle_buffer(SearchType::AllHandles)
+        .expect_success("Failed to locate all handles");
+    let handles = bt
+        .locate_handle_buffer(SearchType::ByProtocol(&LoadedImage::GUID))
+        .expect_success("Failed to locate handles supporting LoadedImage");
+
+    info!(
+        "{} of {} handles support LoadedImage",
+        handles.len(),
+        all_handles.len()
+    );
+    // At least this image must be loaded.
+    assert!(!handles.is_empty());
+    assert!(handles.len() <= all_handles.len());
+}
+
 mod console;
 mod debug;
 mod device_path;
 mod loaded_image;
 mod media;
+mod misc;
+mod network;
 mod pi;
+mod rng;
+mod security;
+mod shell;
 #[cfg(any(
     target_arch = "i386",
     target_arch = "x86_64",
@@ -67,3 +104,4 @@ mod pi;
     target_arch = "aarch64"
 ))]
 mod shim;
+mod string;
@@ -0,0 +1,206 @@
+use core::ptr;
+use uefi::cstr16;
+use uefi::data_types::Align;
+use uefi::prelude::*;
+use uefi::proto::network::dns::{Dns4, Dns4ConfigData, Dns4ServiceBinding};
+use uefi::proto::network::ip4config2::{Ip4Config2, Ip4Config2InterfaceInfo};
+use uefi::proto::network::pxe::BaseCode;
+use uefi::proto::network::snp::{NetworkState, SimpleNetwork};
+use uefi::table::boot::{OpenProtocolAttributes, OpenProtocolParams};
+
+pub fn test(bt: &BootServices) {
+    info!("Running simple network protocol test");
+    if let Ok(snp) = bt.locate_protocol::<SimpleNetwork>() {
+        let snp = snp.expect("Warnings encountered while opening simple network protocol");
+        let snp = unsafe { &mut *snp.get() };
+
+        // The firmware's network stack may already have started the
+        // interface.
+        if snp.mode().state == NetworkState::STOPPED {
+            snp.start()
+                .expect_success("Failed to start network interface");
+        }
+        if snp.mode().state == NetworkState::STARTED {
+            snp.initialize(0, 0)
+                .expect_success("Failed to initialize network interface");
+        }
+        assert_eq!(snp.mode().state, NetworkState::INITIALIZED);
+
+        let mode = snp.mode();
+        let mac = &mode.current_address.0[..mode.hw_address_size as usize];
+        info!("MAC address: {:02x?}", mac);
+        assert_eq!(mac.len(), 6);
+        assert!(mac.iter().any(|&b| b != 0));
+
+        // Send a broadcast frame with the local experimental EtherType, and
+        // let the driver fill in the media header.
+        if cfg!(feature = "qemu") {
+            let header_size = mode.media_header_size as usize;
+            let broadcast = mode.broadcast_address;
+            let mut frame = [0; 64];
+            frame[header_size..header_size + 5].copy_from_slice(b"hello");
+            let frame_ptr = frame.as_ptr();
+            unsafe { snp.transmit(header_size, &frame, None, Some(broadcast), Some(0x88b5)) }
+                .expect_success("Failed to transmit frame");
+
+            // Wait up to a second for the driver to give the buffer back.
+            let recycled = (0..1000).any(|_| {
+                let buf = snp
+                    .get_recycled_transmit_buffer()
+                    .expect_success("Failed to get transmit status");
+                match buf {
+                    Some(buf) => ptr::eq(buf.as_ptr(), frame_ptr),
+                    None => {
+                        bt.stall(1000);
+                        false
+                    }
+                }
+            });
+            assert!(recycled, "Transmit buffer was not recycled");
+        }
+
+        // Polling for a frame must not block.
+        let mut buffer = [0; 1514];
+        let frame = snp
+            .receive(&mut buffer)
+            .expect_success("Failed to receive frame");
+        info!("Received frame: {:?}", frame);
+    } else {
+        warn!("Simple network protocol is not supported");
+    }
+}
+
+pub fn test_pxe(bt: &BootServices) {
+    info!("Running PXE base code protocol test");
+
+    // DHCP only works with the network set up by QEMU.
+    if !cfg!(feature = "qemu") {
+        return;
+    }
+
+    if let Ok(pxe) = bt.locate_protocol::<BaseCode>() {
+        let pxe = pxe.expect("Warnings encountered while opening PXE base code protocol");
+        let pxe = unsafe { &mut *pxe.get() };
+
+        if !pxe.mode().started {
+            pxe.start(false)
+                .expect_success("Failed to start PXE base code");
+        }
+        pxe.dhcp(false).expect_success("DHCP failed");
+
+        let mode = pxe.mode();
+        assert!(mode.dhcp_ack_received);
+        let ip = mode.station_ip.as_v4();
+        info!("Assigned IP address: {:?}", ip);
+        assert_ne!(ip, [0; 4]);
+    } else {
+        warn!("PXE base code protocol is not supported");
+    }
+}
+
+pub fn test_ip4config2(bt: &BootServices) {
+    info!("Running IPv4 configuration protocol test");
+    if let Ok(ip4config2) = bt.locate_protocol::<Ip4Config2>() {
+        let ip4config2 =
+            ip4config2.expect("Warnings encountered while opening IPv4 config protocol");
+        let ip4config2 = unsafe { &*ip4config2.get() };
+
+        let policy = ip4config2
+            .policy()
+            .expect_success("Failed to get IPv4 config policy");
+        info!("IPv4 config policy: {:?}", policy);
+
+        let mut storage = vec![0; 512];
+        let buffer = Ip4Config2InterfaceInfo::align_buf(&mut storage).unwrap();
+        let info = ip4config2
+            .interface_info(buffer)
+            .expect_success("Failed to get interface info");
+        info!(
+            "Interface {:?}: station address {:?}, routes {:?}",
+            info.name(),
+            info.station_address,
+            info.route_table()
+        );
+    } else {
+        warn!("IPv4 config protocol is not supported");
+    }
+}
+
+pub fn test_dns(image: Handle, bt: &BootServices) {
+    info!("Running DNS protocol test");
+
+    // Resolving a host name needs the network set up by QEMU, and access to
+    // the outside world, which the CI doesn't have.
+    if !cfg!(feature = "qemu") || cfg!(feature = "ci") {
+        return;
+    }
+
+    let handle = match bt.find_handles::<Dns4ServiceBinding>() {
+        Ok(handles) => handles.unwrap()[0],
+        Err(_) => {
+            warn!("DNS protocol is not supported");
+            return;
+        }
+    };
+    let binding = bt
+        .open_protocol::<Dns4ServiceBinding>(
+            OpenProtocolParams {
+                handle,
+                agent: image,
+                controller: None,
+            },
+            OpenProtocolAttributes::GetProtocol,
+        )
+        .expect_success("Failed to open DNS service binding protocol");
+    let binding = unsafe { &mut *binding.interface.get() };
+
+    let child = binding
+        .create_child()
+        .expect_success("Failed to create DNS instance");
+    let dns = bt
+        .open_protocol::<Dns4>(
+            OpenProtocolParams {
+                handle: child,
+                agent: image,
+                controller: None,
+            },
+            OpenProtocolAttributes::GetProtocol,
+        )
+        .expect_success("Failed to open DNS protocol");
+    let dns = unsafe { &mut *dns.interface.get() };
+
+    // The station address is not available until DHCP has completed.
+    let config = Dns4ConfigData::new(&[]);
+    let mut attempts = 0;
+    loop {
+        match dns.configure(&config) {
+            Err(err) if err.status() == Status::NO_MAPPING && attempts < 10 => {
+                attempts += 1;
+                bt.stall(1_000_000);
+            }
+            result => {
+                result.expect_success("Failed to configure DNS instance");
+                break;
+            }
+        }
+    }
+
+    let host_name = cstr16!("example.com");
+    let addresses = dns
+        .host_name_to_ip(bt, host_name)
+        .expect_success("Failed to resolve host name");
+    info!("{} resolves to {:?}", host_name, addresses);
+    assert!(!addresses.is_empty());
+
+    let mut buffer = [[0; 4]; 16];
+    let addresses_with_buf = dns
+        .host_name_to_ip_with_buf(bt, host_name, &mut buffer)
+        .expect_success("Failed to resolve host name");
+    assert_eq!(addresses_with_buf.len(), addresses.len());
+
+    dns.reset_configuration()
+        .expect_success("Failed to reset DNS instance");
+    binding
+        .destroy_child(child)
+        .expect_success("Failed to destroy DNS instance");
+}
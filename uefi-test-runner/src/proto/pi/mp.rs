@@ -33,6 +33,10 @@ pub fn test(bt: &BootServices) {
 
 fn test_get_number_of_processors(mps: &MpServices) {
     let proc_count = mps.get_number_of_processors().unwrap().unwrap();
+    info!(
+        "{} processors, {} enabled",
+        proc_count.total, proc_count.enabled
+    );
 
     // Ensure we can see all of the requested CPUs
     assert_eq!(proc_count.total, NUM_CPUS);
@@ -0,0 +1,29 @@
+use uefi::prelude::*;
+use uefi::proto::rng::Rng;
+use uefi::Guid;
+
+pub fn test(bt: &BootServices) {
+    info!("Running rng protocol test");
+    if let Ok(rng) = bt.locate_protocol::<Rng>() {
+        let rng = rng.expect("Warnings encountered while opening rng protocol");
+        let rng = unsafe { &*rng.get() };
+
+        let mut algorithm_buf = [Guid::default(); 16];
+        let algorithms = rng
+            .get_info(&mut algorithm_buf)
+            .expect_success("Failed to get rng algorithms");
+        info!("Supported rng algorithms: {:?}", algorithms);
+
+        let mut buffer = [0u8; 16];
+        rng.get_rng(None, &mut buffer)
+            .expect_success("Failed to get random bytes");
+        info!("Random bytes: {:?}", buffer);
+
+        if let Some(algorithm) = algorithms.first() {
+            rng.get_rng(Some(*algorithm), &mut buffer)
+                .expect_success("Failed to get random bytes using a specific algorithm");
+        }
+    } else {
+        warn!("Rng protocol is not supported");
+    }
+}
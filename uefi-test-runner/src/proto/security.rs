@@ -0,0 +1,49 @@
+use uefi::prelude::*;
+use uefi::proto::security::{Hash2, HASH_ALGORITHM_SHA256};
+
+pub fn test(bt: &BootServices) {
+    info!("Running hash2 protocol test");
+    if let Ok(hash2) = bt.locate_protocol::<Hash2>() {
+        let hash2 = hash2.expect("Warnings encountered while opening hash2 protocol");
+        let hash2 = unsafe { &mut *hash2.get() };
+
+        // SHA-256 digest of "abc", from FIPS 180-2.
+        let expected = [
+            0xba, 0x78, 0x16, 0xbf, 0x8f, 0x01, 0xcf, 0xea, 0x41, 0x41, 0x40, 0xde, 0x5d, 0xae,
+            0x22, 0x23, 0xb0, 0x03, 0x61, 0xa3, 0x96, 0x17, 0x7a, 0x9c, 0xb4, 0x10, 0xff, 0x61,
+            0xf2, 0x00, 0x15, 0xad,
+        ];
+
+        let size = hash2
+            .get_hash_size(HASH_ALGORITHM_SHA256)
+            .expect_success("Failed to get SHA-256 digest size");
+        assert_eq!(size, 32);
+
+        let mut digest = [0; 32];
+        hash2
+            .hash(HASH_ALGORITHM_SHA256, b"abc", &mut digest)
+            .expect_success("Failed to hash data");
+        assert_eq!(digest, expected);
+
+        let state = hash2
+            .hash_init(HASH_ALGORITHM_SHA256)
+            .expect_success("Failed to start hashing");
+        assert_eq!(state.hash_size(), 32);
+        hash2
+            .hash_update(b"a")
+            .expect_success("Failed to hash data");
+        hash2
+            .hash_update(b"bc")
+            .expect_success("Failed to hash data");
+        // The buffer may be longer than the digest, which only fills the
+        // start of it.
+        let mut digest = [0xff; 40];
+        hash2
+            .hash_final(state, &mut digest)
+            .expect_success("Failed to finish hashing");
+        assert_eq!(digest[..32], expected);
+        assert_eq!(digest[32..], [0xff; 8]);
+    } else {
+        warn!("Hash2 protocol is not supported");
+    }
+}
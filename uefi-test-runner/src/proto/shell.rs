@@ -0,0 +1,57 @@
+use uefi::cstr16;
+use uefi::prelude::*;
+use uefi::proto::shell::Shell;
+use uefi::proto::shell_params::ShellParameters;
+use uefi::table::boot::{OpenProtocolAttributes, OpenProtocolParams};
+
+pub fn test(bt: &BootServices) {
+    info!("Running shell protocol test");
+    if let Ok(shell) = bt.locate_protocol::<Shell>() {
+        let shell = shell.expect("Warnings encountered while opening shell protocol");
+        let shell = unsafe { &mut *shell.get() };
+
+        info!("Current directory: {:?}", shell.get_cur_dir());
+
+        let name = cstr16!("uefi_test_runner");
+        shell
+            .set_env(name, cstr16!("value"), true)
+            .expect_success("Failed to set shell variable");
+        assert_eq!(shell.get_env(name), Some(cstr16!("value")));
+
+        // Setting an empty value deletes the variable.
+        shell
+            .set_env(name, cstr16!(""), true)
+            .expect_success("Failed to delete shell variable");
+        assert_eq!(shell.get_env(name), None);
+    } else {
+        // The test runner is usually not launched from the shell.
+        warn!("Shell protocol is not supported");
+    }
+}
+
+pub fn test_params(image: Handle, bt: &BootServices) {
+    info!("Running shell parameters protocol test");
+    let params = bt.open_protocol::<ShellParameters>(
+        OpenProtocolParams {
+            handle: image,
+            agent: image,
+            controller: None,
+        },
+        OpenProtocolAttributes::GetProtocol,
+    );
+    if let Ok(params) = params {
+        let params = params.expect("Warnings encountered while opening shell parameters protocol");
+        let params = unsafe { &*params.interface.get() };
+
+        let args = params.args();
+        info!("Number of arguments: {}", args.len());
+        for arg in args {
+            info!("Argument: {}", arg);
+        }
+        let argv0 = params.args().next().expect("Missing argv[0]");
+        assert!(!argv0.is_empty());
+    } else {
+        // The test runner is usually not launched from the shell.
+        warn!("Shell parameters protocol is not supported");
+    }
+}
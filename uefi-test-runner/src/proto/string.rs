@@ -0,0 +1,51 @@
+use core::cmp::Ordering;
+use uefi::prelude::*;
+use uefi::proto::string::UnicodeCollation;
+use uefi::{cstr16, CStr8};
+
+pub fn test(bt: &BootServices) {
+    info!("Running Unicode collation protocol test");
+    if let Ok(collation) = bt.locate_protocol::<UnicodeCollation>() {
+        let collation =
+            collation.expect("Warnings encountered while opening Unicode collation protocol");
+        let collation = unsafe { &*collation.get() };
+
+        info!("Supported languages: {}", collation.supported_languages());
+
+        assert_eq!(
+            collation.stri_coll(cstr16!("Hello"), cstr16!("hELLO")),
+            Ordering::Equal
+        );
+        assert_eq!(
+            collation.stri_coll(cstr16!("abc"), cstr16!("ABD")),
+            Ordering::Less
+        );
+        // OVMF's English collation also folds the case of Latin-1 letters.
+        assert_eq!(
+            collation.stri_coll(cstr16!("Éa"), cstr16!("éA")),
+            Ordering::Equal
+        );
+
+        assert!(collation.metai_match(cstr16!("Test.EFI"), cstr16!("*.efi")));
+        assert!(collation.metai_match(cstr16!("abc"), cstr16!("A?[a-c]")));
+        assert!(!collation.metai_match(cstr16!("abc"), cstr16!("ab")));
+
+        let mut buf = [0; 16];
+        let s = collation.str_lwr(cstr16!("MiXeD"), &mut buf).unwrap();
+        assert_eq!(s, cstr16!("mixed"));
+        let s = collation.str_upr(cstr16!("MiXeD"), &mut buf).unwrap();
+        assert_eq!(s, cstr16!("MIXED"));
+        assert!(collation.str_upr(cstr16!("MiXeD"), &mut buf[..5]).is_err());
+
+        let fat = CStr8::from_bytes_with_nul(b"BOOTX64EFI\0").unwrap();
+        let s = collation.fat_to_str(fat, &mut buf).unwrap();
+        assert_eq!(s, cstr16!("BOOTX64EFI"));
+
+        let mut fat_buf = [0; 16];
+        let (fat, substituted) = collation.str_to_fat(cstr16!("boot"), &mut fat_buf).unwrap();
+        assert_eq!(fat.to_bytes(), b"BOOT");
+        assert!(!substituted);
+    } else {
+        warn!("Unicode collation protocol is not supported");
+    }
+}
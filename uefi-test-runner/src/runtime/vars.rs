@@ -28,6 +28,14 @@ fn test_variables(rt: &RuntimeServices) {
         .expect_success("failed to get variable size");
     assert_eq!(size, test_value.len());
 
+    info!("Testing get_variable with a buffer that is too small");
+    let mut buf = [0u8; 4];
+    let err = rt
+        .get_variable(name, &vendor, &mut buf)
+        .expect_err("get_variable should fail with a small buffer");
+    assert_eq!(err.status(), Status::BUFFER_TOO_SMALL);
+    assert_eq!(*err.data(), Some(test_value.len()));
+
     info!("Testing get_variable");
     let mut buf = [0u8; 9];
     let (data, attrs) = rt
@@ -46,8 +54,41 @@ fn test_variables(rt: &RuntimeServices) {
     if let Some(key) = variable_keys.first() {
         info!("First variable: {}", key);
     }
+
+    // OVMF always sets some of the global variables defined by the spec.
+    let mut buf = [0; 13];
+    let platform_lang = CStr16::from_str_with_buf("PlatformLang", &mut buf).unwrap();
+    let mut buf = [0; 10];
+    let boot_order = CStr16::from_str_with_buf("BootOrder", &mut buf).unwrap();
+    assert!(
+        variable_keys.iter().any(|key| {
+            key.vendor == VariableVendor::GLOBAL_VARIABLE
+                && key
+                    .name()
+                    .map_or(false, |name| name == platform_lang || name == boot_order)
+        }),
+        "neither PlatformLang nor BootOrder were found"
+    );
+}
+
+fn test_query_variable_info(rt: &RuntimeServices) {
+    info!("Testing query_variable_info");
+    let attrs = VariableAttributes::NON_VOLATILE
+        | VariableAttributes::BOOTSERVICE_ACCESS
+        | VariableAttributes::RUNTIME_ACCESS;
+    let storage = rt
+        .query_variable_info(attrs)
+        .expect_success("failed to query variable info");
+    info!(
+        "Free NVRAM: {} of {} bytes, largest variable: {} bytes",
+        storage.remaining_variable_storage_size,
+        storage.maximum_variable_storage_size,
+        storage.maximum_variable_size
+    );
+    assert!(storage.remaining_variable_storage_size <= storage.maximum_variable_storage_size);
 }
 
 pub fn test(rt: &RuntimeServices) {
     test_variables(rt);
+    test_query_variable_info(rt);
 }
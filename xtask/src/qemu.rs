@@ -332,6 +332,15 @@ pub fn run_qemu(arch: UefiArch, opt: &QemuOpt) -> Result<()> {
     drive_arg.push(esp_dir);
     cmd.arg(drive_arg);
 
+    // Provide an entropy source, so that the firmware installs the RNG
+    // protocol.
+    cmd.args(&["-device", "virtio-rng-pci"]);
+
+    // Provide a network interface, so that the firmware installs the
+    // Simple Network protocol.
+    cmd.args(&["-netdev", "user,id=net0"]);
+    cmd.args(&["-device", "virtio-net-pci,netdev=net0"]);
+
     // When running in headless mode we don't have video, but we can still have
     // QEMU emulate a display and take screenshots from it.
     cmd.args(&["-vga", "std"]);